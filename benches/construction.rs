@@ -0,0 +1,65 @@
+//! Measures the cost of creating a `sync::Gen`, with and without recycling the
+//! allocations of a finished generator.
+//!
+//! Run with `cargo bench --bench construction`. `Gen::new` allocates once for
+//! the future and once for the airlock, while `Gen::new_from_boxed` reuses
+//! both, so the difference between the two is the cost of those allocations.
+
+#![warn(future_incompatible, rust_2018_compatibility, rust_2018_idioms, unused)]
+#![warn(clippy::pedantic)]
+#![cfg_attr(feature = "strict", deny(warnings))]
+
+use genawaiter::{
+    sync::{Co, Gen},
+    GeneratorState,
+};
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+const GENERATORS: u64 = 1_000_000;
+
+async fn produce(start: u64, mut co: Co<u64>) {
+    co.yield_(start).await;
+}
+
+fn first(gen: &mut Gen<u64, (), impl Future<Output = ()>>) -> u64 {
+    match gen.resume() {
+        GeneratorState::Yielded(n) => n,
+        GeneratorState::Complete(()) => unreachable!(),
+    }
+}
+
+// Inline format arguments need Rust 1.58.
+#[allow(clippy::uninlined_format_args)]
+fn report(name: &str, elapsed: Duration, total: u64) {
+    // Printing the total keeps the loop from being optimized away.
+    #[allow(clippy::cast_precision_loss)]
+    let per_generator = elapsed.as_nanos() as f64 / GENERATORS as f64;
+    println!(
+        "{:<15} {:>8.2} ns/generator (total {})",
+        name, per_generator, total
+    );
+}
+
+fn main() {
+    let start = Instant::now();
+    let mut total = 0;
+    for i in 0..GENERATORS {
+        let mut gen = Gen::new(|co| produce(i, co));
+        total += first(&mut gen);
+    }
+    report("new", start.elapsed(), total);
+
+    let mut gen = Gen::new(|co| produce(0, co));
+    while let GeneratorState::Yielded(_) = gen.resume() {}
+    let start = Instant::now();
+    let mut total = 0;
+    for i in 0..GENERATORS {
+        let (storage, airlock, ..) = gen.into_parts();
+        gen = Gen::new_from_boxed(storage, airlock, |co| produce(i, co));
+        total += first(&mut gen);
+    }
+    report("new_from_boxed", start.elapsed(), total);
+}
@@ -0,0 +1,62 @@
+//! Measures the cost of a single resume, which is dominated by the airlock.
+//!
+//! Run with `cargo bench --bench resume`. Each `rc` transition is a single
+//! `Cell::replace`, and each `sync` transition takes the airlock's lock, so the
+//! difference between the two is the cost of that lock.
+
+#![warn(future_incompatible, rust_2018_compatibility, rust_2018_idioms, unused)]
+#![warn(clippy::pedantic)]
+#![cfg_attr(feature = "strict", deny(warnings))]
+
+use genawaiter::{rc, sync, GeneratorState};
+use std::time::{Duration, Instant};
+
+const RESUMES: u64 = 10_000_000;
+
+async fn count_rc(mut co: rc::Co<u64, u64>) {
+    let mut total = 0;
+    loop {
+        total += co.yield_(total).await;
+    }
+}
+
+async fn count_sync(mut co: sync::Co<u64, u64>) {
+    let mut total = 0;
+    loop {
+        total += co.yield_(total).await;
+    }
+}
+
+// Inline format arguments need Rust 1.58.
+#[allow(clippy::uninlined_format_args)]
+fn report(name: &str, elapsed: Duration, total: u64) {
+    // Printing the total keeps the loop from being optimized away.
+    #[allow(clippy::cast_precision_loss)]
+    let per_resume = elapsed.as_nanos() as f64 / RESUMES as f64;
+    println!(
+        "{:<6} {:>8.2} ns/resume (total {})",
+        name, per_resume, total
+    );
+}
+
+fn main() {
+    let mut gen = rc::Gen::new(count_rc);
+    let start = Instant::now();
+    let mut total = 0;
+    for i in 0..RESUMES {
+        if let GeneratorState::Yielded(n) = gen.resume_with(i) {
+            total = n;
+        }
+    }
+    report("rc", start.elapsed(), total);
+
+    let mut gen = sync::Gen::new(count_sync);
+    let start = Instant::now();
+    let mut total = 0;
+    for i in 0..RESUMES {
+        if let GeneratorState::Yielded(n) = gen.resume_with(i) {
+            total = n;
+        }
+    }
+    report("sync", start.elapsed(), total);
+}
@@ -0,0 +1,61 @@
+//! This is a `#![no_std]` library which uses generators.
+//!
+//! It serves as a build test for the crate without the `std` feature. Build it for
+//! a target without `std` (e.g. `thumbv7m-none-eabi`) to make sure nothing from
+//! `std` sneaks back in.
+
+#![no_std]
+#![warn(future_incompatible, rust_2018_compatibility, rust_2018_idioms, unused)]
+#![warn(clippy::pedantic)]
+#![cfg_attr(feature = "strict", deny(warnings))]
+
+use genawaiter::{rc, stack::let_gen_using, sync, GeneratorState};
+
+/// Sums the odd numbers under ten, using each flavor of generator.
+#[must_use]
+pub fn sum_odd_numbers() -> [i32; 3] {
+    let rc_gen = rc::Gen::new(odd_numbers_rc);
+    let sync_gen = sync::Gen::new(odd_numbers_sync);
+    let_gen_using!(stack_gen, odd_numbers_stack);
+    [sum(rc_gen), sum(sync_gen), stack_gen.into_iter().sum()]
+}
+
+fn sum(gen: impl IntoIterator<Item = i32>) -> i32 {
+    gen.into_iter().sum()
+}
+
+async fn odd_numbers_rc(mut co: rc::Co<i32>) {
+    for n in (1..10).step_by(2) {
+        co.yield_(n).await;
+    }
+}
+
+async fn odd_numbers_sync(mut co: sync::Co<i32>) {
+    for n in (1..10).step_by(2) {
+        co.yield_(n).await;
+    }
+}
+
+async fn odd_numbers_stack(mut co: genawaiter::stack::Co<'_, i32>) {
+    for n in (1..10).step_by(2) {
+        co.yield_(n).await;
+    }
+}
+
+/// Runs a generator which counts down from `n`, and returns its completion value.
+#[must_use]
+pub fn countdown(n: u32) -> &'static str {
+    let mut gen = rc::Gen::new(|mut co| {
+        async move {
+            for i in (0..n).rev() {
+                co.yield_(i).await;
+            }
+            "liftoff"
+        }
+    });
+    loop {
+        if let GeneratorState::Complete(value) = gen.resume() {
+            return value;
+        }
+    }
+}
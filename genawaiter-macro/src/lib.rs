@@ -5,6 +5,13 @@
 #[macro_export]
 #[cfg(feature = "proc_macro")]
 macro_rules! stack_let_gen {
+    ($name:ident,resume = $resume:ty, $body:expr $(,)?) => {
+        ::genawaiter::stack::let_gen_using!(
+            $name,
+            resume = $resume,
+            ::genawaiter::stack_producer!($body),
+        );
+    };
     ($name:ident, $body:expr $(,)?) => {
         ::genawaiter::stack::let_gen_using!(
             $name,
@@ -15,6 +22,15 @@ macro_rules! stack_let_gen {
 
 #[macro_export]
 macro_rules! stack_let_gen_using {
+    ($name:ident,resume = $resume:ty, $producer:expr $(,)?) => {
+        // Safety: This is the same as the form below, except that the resume type
+        // is spelled out. The variables are still hygienic.
+        let mut shelf = ::genawaiter::stack::Shelf::new();
+        let mut generator = unsafe {
+            ::genawaiter::stack::Gen::<_, $resume, _>::new(&mut shelf, $producer)
+        };
+        let $name = &mut generator;
+    };
     ($name:ident, $producer:expr $(,)?) => {
         // Safety: The goal here is to ensure the safety invariants of `Gen::new`, i.e.,
         // the lifetime of the `Co` argument (in `$producer`) must not outlive `shelf`
@@ -1,19 +1,37 @@
-use crate::{ops::GeneratorState, waker};
-use std::{
+use crate::{
+    ops::{Coroutine, GeneratorState},
+    waker,
+};
+use alloc::vec::Vec;
+use core::{
     future::Future,
     pin::Pin,
     task::{Context, Poll},
 };
 
+/// The contents of an airlock.
+///
+/// This is exposed for debugging, and for custom drivers. See [`Airlock`] for
+/// how it changes over the life of a generator.
+#[derive(PartialEq, Eq, Debug)]
 pub enum Next<Y, R> {
+    /// Nothing is waiting to be passed along.
     Empty,
+
+    /// The generator yielded a value, which the caller has not taken yet.
     Yield(Y),
+
+    /// The caller resumed the generator with a value, which the generator has
+    /// not taken yet.
     Resume(R),
+
+    /// The generator completed. This is only tracked in debug builds.
     Completed,
 }
 
 #[allow(clippy::use_self)]
 impl<Y, R> Next<Y, R> {
+    /// Returns which variant this is, without the value inside.
     pub fn without_values(&self) -> Next<(), ()> {
         match self {
             Self::Empty => Next::Empty,
@@ -72,50 +90,96 @@ struct Advance<'a, F: Future, A: Airlock> {
 }
 
 impl<'a, F: Future, A: Airlock> Advance<'a, F, A> {
-    fn future_mut(self: Pin<&mut Self>) -> Pin<&mut F> {
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut F>, &A) {
         // Safety: This is just projecting a pinned reference. Neither `self` nor
         // `self.future` are moved.
-        unsafe { self.map_unchecked_mut(|s| s.future.as_mut().get_unchecked_mut()) }
+        let this = unsafe { self.get_unchecked_mut() };
+        (this.future.as_mut(), &this.airlock)
     }
 }
 
 impl<'a, F: Future, A: Airlock> Future for Advance<'a, F, A> {
     type Output = GeneratorState<A::Yield, F::Output>;
 
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        match self.as_mut().future_mut().poll(cx) {
-            Poll::Pending => {
-                let value = self.airlock.replace(Next::Empty);
-                match value {
-                    Next::Empty | Next::Resume(_) => Poll::Pending,
-                    Next::Yield(y) => Poll::Ready(GeneratorState::Yielded(y)),
-                    Next::Completed => unreachable!(),
-                }
-            }
-            Poll::Ready(value) => {
-                #[cfg(debug_assertions)]
-                self.airlock.replace(Next::Completed);
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let (future, airlock) = self.project();
+        poll_advance(future, airlock, cx)
+    }
+}
 
-                Poll::Ready(GeneratorState::Complete(value))
+/// Polls the future once using the caller's context.
+///
+/// The airlock tells apart the two reasons the future might be pending: if it
+/// holds a value, the generator yielded it, otherwise the generator is waiting
+/// on some other future, which will wake `cx` when it is ready.
+pub fn poll_advance<Y, R, F: Future>(
+    future: Pin<&mut F>,
+    airlock: &impl Airlock<Yield = Y, Resume = R>,
+    cx: &mut Context<'_>,
+) -> Poll<GeneratorState<Y, F::Output>> {
+    match future.poll(cx) {
+        Poll::Pending => {
+            let value = airlock.replace(Next::Empty);
+            match value {
+                Next::Empty | Next::Resume(_) => Poll::Pending,
+                Next::Yield(y) => Poll::Ready(GeneratorState::Yielded(y)),
+                Next::Completed => unreachable!(),
             }
         }
+        Poll::Ready(value) => {
+            #[cfg(debug_assertions)]
+            airlock.replace(Next::Completed);
+
+            Poll::Ready(GeneratorState::Complete(value))
+        }
     }
 }
 
-pub trait Airlock {
+/// The slot through which values pass between a generator and its caller.
+///
+/// This trait is sealed, so it cannot be implemented outside this crate. The
+/// airlock of an `rc` or `sync` generator can be reached through the `airlock`
+/// method of [`rc::Co`](crate::rc::Co) or [`sync::Co`](crate::sync::Co), and
+/// cloning it gives another handle to the same slot.
+///
+/// Over the life of a generator, the slot follows these steps, and a custom
+/// driver which calls `replace` must stick to them:
+///
+/// 1. Before the generator's future is polled, the caller stores
+///    `Next::Resume(arg)`.
+/// 2. When the generator yields, `Co::yield_` stores `Next::Yield(value)`, and
+///    the future returns `Poll::Pending`.
+/// 3. The caller takes the yielded value by storing `Next::Empty`. If the slot
+///    still holds `Next::Resume` after the future returns `Poll::Pending`, the
+///    generator is waiting on some other future instead.
+/// 4. On the next poll, the future awaiting `yield_` takes the resume argument
+///    by storing `Next::Empty`, and the generator carries on.
+///
+/// Breaking these steps does not cause undefined behavior, but the generator
+/// will panic, or values will be lost.
+pub trait Airlock: sealed::Sealed {
+    /// The type of value the generator yields.
     type Yield;
+
+    /// The type of value the generator is resumed with.
     type Resume;
 
+    /// Returns which variant of [`Next`] is stored, without the value inside.
     fn peek(&self) -> Next<(), ()>;
 
+    /// Stores `next`, and returns what was stored before.
     fn replace(
         &self,
         next: Next<Self::Yield, Self::Resume>,
     ) -> Next<Self::Yield, Self::Resume>;
 }
 
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
 pub struct Co<A: Airlock> {
-    airlock: A,
+    pub(crate) airlock: A,
 }
 
 impl<A: Airlock> Co<A> {
@@ -127,6 +191,11 @@ impl<A: Airlock> Co<A> {
     ///
     /// The caller should immediately `await` the result of this function.
     ///
+    /// If the generator is dropped while it is paused here, the returned future
+    /// is dropped along with the rest of the generator's state, so the
+    /// destructors of any values still alive in the generator are run, as with
+    /// any cancelled future. A drop guard can be used to run cleanup code then.
+    ///
     /// [_See the module-level docs for examples._](.)
     pub fn yield_(&mut self, value: A::Yield) -> impl Future<Output = A::Resume> + '_ {
         #[cfg(debug_assertions)]
@@ -151,6 +220,97 @@ impl<A: Airlock> Co<A> {
             airlock: &self.airlock,
         }
     }
+
+    /// Yields each value from an iterator, in order.
+    ///
+    /// Returns the resume arguments received after each value was yielded.
+    ///
+    /// The caller should immediately `await` the result of this function.
+    pub async fn yield_all_with<I>(&mut self, values: I) -> Vec<A::Resume>
+    where
+        I: IntoIterator<Item = A::Yield>,
+    {
+        let mut resume_args = Vec::new();
+        for value in values {
+            resume_args.push(self.yield_(value).await);
+        }
+        resume_args
+    }
+
+    /// Yields values from `produce` for as long as `cond` returns `true`.
+    ///
+    /// `cond` is checked before each value is produced. Returns the resume
+    /// arguments received after each value was yielded.
+    ///
+    /// The caller should immediately `await` the result of this function.
+    pub async fn yield_while_with<C, P>(
+        &mut self,
+        mut cond: C,
+        mut produce: P,
+    ) -> Vec<A::Resume>
+    where
+        C: FnMut() -> bool,
+        P: FnMut() -> A::Yield,
+    {
+        let mut resume_args = Vec::new();
+        while cond() {
+            resume_args.push(self.yield_(produce()).await);
+        }
+        resume_args
+    }
+
+    /// Runs another coroutine to completion, yielding each of its values from
+    /// this generator.
+    ///
+    /// Each resume argument this generator receives is passed along to `other`,
+    /// and the value `other` returns is returned from this function. Since the
+    /// first resume argument of a coroutine is never observed, `other` is
+    /// first resumed with `Default::default()`.
+    ///
+    /// The caller should immediately `await` the result of this function.
+    pub async fn yield_from<C>(&mut self, other: C) -> C::Return
+    where
+        C: Coroutine<Yield = A::Yield, Resume = A::Resume>,
+        A::Resume: Default,
+    {
+        let mut other = other;
+        // Safety: `other` lives in the state of this future, which is pinned, and
+        // it is never moved after this point.
+        let mut other = unsafe { Pin::new_unchecked(&mut other) };
+        let mut arg = A::Resume::default();
+        loop {
+            match other.as_mut().resume_with(arg) {
+                GeneratorState::Yielded(value) => arg = self.yield_(value).await,
+                GeneratorState::Complete(value) => return value,
+            }
+        }
+    }
+}
+
+impl<A: Airlock<Resume = ()>> Co<A> {
+    /// Yields each value from an iterator, in order.
+    ///
+    /// The caller should immediately `await` the result of this function.
+    pub async fn yield_all<I: IntoIterator<Item = A::Yield>>(&mut self, values: I) {
+        for value in values {
+            self.yield_(value).await;
+        }
+    }
+
+    /// Yields values from `produce` for as long as `cond` returns `true`.
+    ///
+    /// `cond` is checked before each value is produced.
+    ///
+    /// The caller should immediately `await` the result of this function.
+    pub async fn yield_while<C, P>(&mut self, mut cond: C, mut produce: P)
+    where
+        C: FnMut() -> bool,
+        P: FnMut() -> A::Yield,
+    {
+        while cond() {
+            self.yield_(produce()).await;
+        }
+    }
 }
 
 struct Barrier<'a, A: Airlock> {
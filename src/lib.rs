@@ -13,10 +13,15 @@ with this crate, you can use them on stable Rust!
 
 This crate has these features:
 
+- `control_flow` (disabled by default) – Adds conversions between
+  [`GeneratorState`] and `core::ops::ControlFlow`. This needs Rust 1.55 or later.
 - `futures03` (disabled by default) – Implements `Stream` for all generator types.
   Adds a dependency on `futures-core`.
 - `proc_macro` (enabled by default) – Adds support for macros, and adds various
   compile-time dependencies.
+- `std` (enabled by default) – Links the standard library. Without it, this crate is
+  `#![no_std]`, and only needs an allocator via `alloc`. A few panic-related helpers,
+  such as [`sync::Gen::resume_with_catch_unwind`], require this feature.
 
 # Choose your guarantees
 
@@ -256,15 +261,29 @@ stdlib. A `Coroutine` is a generalization of a `Generator`. A `Generator` constr
 resume argument type to `()`, but in a `Coroutine` it can be anything.
 */
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![cfg_attr(feature = "nightly", feature(async_closure))]
 #![warn(future_incompatible, rust_2018_compatibility, rust_2018_idioms, unused)]
 #![warn(missing_docs, clippy::cargo, clippy::pedantic)]
 #![cfg_attr(feature = "strict", deny(warnings))]
 
+extern crate alloc;
 #[cfg(test)]
 extern crate self as genawaiter;
 
-pub use crate::ops::{Coroutine, Generator, GeneratorState};
+pub use crate::{
+    core::{Airlock, Next},
+    ops::{
+        adapters,
+        BoxedCoroutine,
+        CompletedCoroutine,
+        Coroutine,
+        Generator,
+        GeneratorState,
+        IntoCoroutine,
+        ResumeExt,
+    },
+};
 
 #[cfg(feature = "proc_macro")]
 use proc_macro_hack::proc_macro_hack;
@@ -328,6 +347,7 @@ mod ext;
 mod macros;
 mod ops;
 pub mod rc;
+pub mod simple;
 pub mod stack;
 pub mod sync;
 #[cfg(test)]
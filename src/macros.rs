@@ -30,6 +30,6 @@ macro_rules! pin_mut {
     ($x:ident) => {
         let mut $x = $x;
         #[allow(unused_mut)]
-        let mut $x = unsafe { ::std::pin::Pin::new_unchecked(&mut $x) };
+        let mut $x = unsafe { ::core::pin::Pin::new_unchecked(&mut $x) };
     };
 }
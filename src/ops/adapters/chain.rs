@@ -0,0 +1,118 @@
+use crate::ops::{Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which runs one coroutine to completion, then another.
+///
+/// This is created by [`Coroutine::chain`].
+pub struct Chain<C1, C2> {
+    first: Option<C1>,
+    second: C2,
+}
+
+impl<C1, C2> Chain<C1, C2> {
+    pub(crate) fn new(first: C1, second: C2) -> Self {
+        Self {
+            first: Some(first),
+            second,
+        }
+    }
+
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut Option<C1>>, Pin<&mut C2>) {
+        // Safety: Both fields are structurally pinned. `first` is only ever
+        // cleared through `Pin::set`, which drops it in place.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (
+                Pin::new_unchecked(&mut this.first),
+                Pin::new_unchecked(&mut this.second),
+            )
+        }
+    }
+}
+
+impl<C1, C2> Coroutine for Chain<C1, C2>
+where
+    C1: Coroutine,
+    C1::Resume: Default,
+    C2: Coroutine<Yield = C1::Yield, Resume = C1::Resume>,
+{
+    type Yield = C1::Yield;
+    type Resume = C1::Resume;
+    type Return = C2::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (mut first, second) = self.project();
+        let arg = match first.as_mut().as_pin_mut() {
+            None => arg,
+            Some(inner) => {
+                match inner.resume_with(arg) {
+                    GeneratorState::Yielded(y) => return GeneratorState::Yielded(y),
+                    GeneratorState::Complete(_) => first.set(None),
+                }
+                // The argument was used up by the first coroutine, but the
+                // second one never observes its first resume argument anyway.
+                Default::default()
+            }
+        };
+        second.resume_with(arg)
+    }
+}
+
+impl_into_iterator!(Chain<C1, C2>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    #[test]
+    fn yields_in_order() {
+        let first = Gen::new(|mut co: Co<i32>| {
+            async move {
+                co.yield_(1).await;
+                co.yield_(2).await;
+            }
+        });
+        let second = Gen::new(|mut co: Co<i32>| {
+            async move {
+                co.yield_(3).await;
+            }
+        });
+        let xs: Vec<_> = first.chain(second).into_iter().collect();
+        assert_eq!(xs, [1, 2, 3]);
+    }
+
+    #[test]
+    fn returns_from_second() {
+        let first = Gen::new(|mut co: Co<i32, i32>| {
+            async move {
+                let n = co.yield_(1).await;
+                co.yield_(n).await;
+                "first"
+            }
+        });
+        let second = Gen::new(|mut co: Co<i32, i32>| {
+            async move {
+                let n = co.yield_(10).await;
+                co.yield_(n).await;
+                "second"
+            }
+        });
+        let mut gen = first.chain(second);
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume_with(0), GeneratorState::Yielded(1));
+        assert_eq!(gen.as_mut().resume_with(2), GeneratorState::Yielded(2));
+        assert_eq!(gen.as_mut().resume_with(0), GeneratorState::Yielded(10));
+        assert_eq!(gen.as_mut().resume_with(20), GeneratorState::Yielded(20));
+        assert_eq!(
+            gen.as_mut().resume_with(0),
+            GeneratorState::Complete("second")
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use crate::ops::{adapters::IntoIter, Coroutine, GeneratorState};
+use alloc::vec::Vec;
+use core::pin::Pin;
+
+/// A coroutine which collects the values yielded by another coroutine into
+/// chunks of a fixed size.
+///
+/// This is created by [`Coroutine::chunks`].
+pub struct Chunks<C: Coroutine> {
+    inner: C,
+    size: usize,
+    returned: Option<C::Return>,
+}
+
+impl<C: Coroutine> Chunks<C> {
+    pub(crate) fn new(inner: C, size: usize) -> Self {
+        assert!(size != 0, "`chunks` was called with a size of 0");
+        Self {
+            inner,
+            size,
+            returned: None,
+        }
+    }
+
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut C>, usize, &mut Option<C::Return>) {
+        // Safety: `inner` is structurally pinned, and the other fields are not.
+        // Nothing is moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (
+                Pin::new_unchecked(&mut this.inner),
+                this.size,
+                &mut this.returned,
+            )
+        }
+    }
+}
+
+// `returned` is not structurally pinned.
+impl<C: Coroutine + Unpin> Unpin for Chunks<C> {}
+
+impl<C: Coroutine<Resume = ()>> Coroutine for Chunks<C> {
+    type Yield = Vec<C::Yield>;
+    type Resume = ();
+    type Return = C::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        (): (),
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (mut inner, size, returned) = self.project();
+        if let Some(value) = returned.take() {
+            return GeneratorState::Complete(value);
+        }
+        let mut chunk = Vec::with_capacity(size);
+        while chunk.len() < size {
+            match inner.as_mut().resume_with(()) {
+                GeneratorState::Yielded(y) => chunk.push(y),
+                GeneratorState::Complete(r) => {
+                    if chunk.is_empty() {
+                        return GeneratorState::Complete(r);
+                    }
+                    // Flush the partial chunk first, and complete on the next
+                    // resume.
+                    *returned = Some(r);
+                    break;
+                }
+            }
+        }
+        GeneratorState::Yielded(chunk)
+    }
+}
+
+impl<C: Coroutine<Resume = ()> + Unpin> IntoIterator for Chunks<C> {
+    type Item = Vec<C::Yield>;
+    type IntoIter = IntoIter<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, Generator, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    async fn produce(mut co: Co<i32>) -> &'static str {
+        for i in 0..7 {
+            co.yield_(i).await;
+        }
+        "done"
+    }
+
+    #[test]
+    fn yields_chunks() {
+        let xs: Vec<_> = Gen::new(produce).chunks(3).into_iter().collect();
+        assert_eq!(xs, [vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+    }
+
+    #[test]
+    fn flushes_partial_chunk_before_return() {
+        let mut gen = Gen::new(produce).chunks(4);
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(
+            gen.as_mut().resume(),
+            GeneratorState::Yielded(vec![0, 1, 2, 3])
+        );
+        assert_eq!(
+            gen.as_mut().resume(),
+            GeneratorState::Yielded(vec![4, 5, 6])
+        );
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Complete("done"));
+    }
+
+    #[test]
+    fn exact_multiple() {
+        let mut gen = Gen::new(produce).chunks(7);
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(
+            gen.as_mut().resume(),
+            GeneratorState::Yielded((0..7).collect()),
+        );
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Complete("done"));
+    }
+
+    #[test]
+    #[should_panic(expected = "`chunks` was called with a size of 0")]
+    fn size_of_zero_panics() {
+        let _ = Gen::new(produce).chunks(0);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::ops::{Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which copies the values referenced by another coroutine.
+///
+/// This is created by [`Coroutine::copied`].
+pub struct Copied<C> {
+    inner: C,
+}
+
+impl<C> Copied<C> {
+    pub(crate) fn new(inner: C) -> Self {
+        Self { inner }
+    }
+
+    fn project(self: Pin<&mut Self>) -> Pin<&mut C> {
+        // Safety: `inner` is structurally pinned, and is not moved.
+        unsafe { self.map_unchecked_mut(|this| &mut this.inner) }
+    }
+}
+
+impl<'a, C, T> Coroutine for Copied<C>
+where
+    C: Coroutine<Yield = &'a T>,
+    T: 'a + Copy,
+{
+    type Yield = T;
+    type Resume = C::Resume;
+    type Return = C::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        self.project().resume_with(arg).map_yielded(|&y| y)
+    }
+}
+
+impl_into_iterator!(Copied<C>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    #[test]
+    fn yields_owned_values() {
+        let values = [1, 2, 3];
+        let xs: Vec<i32> = Gen::from_iter(&values).copied().into_iter().collect();
+        assert_eq!(xs, [1, 2, 3]);
+    }
+
+    #[test]
+    fn passes_return_through() {
+        let value = &10;
+        let gen = Gen::new(|mut co: Co<&i32>| {
+            async move {
+                co.yield_(value).await;
+                "done"
+            }
+        });
+        let mut gen = gen.copied();
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume_with(()), GeneratorState::Yielded(10));
+        assert_eq!(
+            gen.as_mut().resume_with(()),
+            GeneratorState::Complete("done"),
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use crate::ops::{adapters::IntoIter, Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which skips values which are equal to the value yielded just
+/// before them, according to a comparison function.
+///
+/// This is created by [`Coroutine::dedup_by`].
+pub struct DedupBy<C: Coroutine, F> {
+    inner: C,
+    eq: F,
+    last: Option<C::Yield>,
+    returned: Option<C::Return>,
+}
+
+impl<C: Coroutine, F> DedupBy<C, F> {
+    pub(crate) fn new(inner: C, eq: F) -> Self {
+        Self {
+            inner,
+            eq,
+            last: None,
+            returned: None,
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn project(
+        self: Pin<&mut Self>,
+    ) -> (
+        Pin<&mut C>,
+        &mut F,
+        &mut Option<C::Yield>,
+        &mut Option<C::Return>,
+    ) {
+        // Safety: `inner` is structurally pinned, and the other fields are not.
+        // Nothing is moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (
+                Pin::new_unchecked(&mut this.inner),
+                &mut this.eq,
+                &mut this.last,
+                &mut this.returned,
+            )
+        }
+    }
+}
+
+// `last` and `returned` are not structurally pinned.
+impl<C: Coroutine + Unpin, F: Unpin> Unpin for DedupBy<C, F> {}
+
+impl<C, F> Coroutine for DedupBy<C, F>
+where
+    C: Coroutine<Resume = ()>,
+    F: FnMut(&C::Yield, &C::Yield) -> bool,
+{
+    type Yield = C::Yield;
+    type Resume = ();
+    type Return = C::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        (): (),
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (mut inner, eq, last, returned) = self.project();
+        if let Some(value) = returned.take() {
+            return GeneratorState::Complete(value);
+        }
+        // The most recent value is held back until a different value arrives,
+        // so it can be compared without being cloned.
+        loop {
+            match inner.as_mut().resume_with(()) {
+                GeneratorState::Yielded(y) => {
+                    match last {
+                        Some(prev) if eq(prev, &y) => {}
+                        Some(_) => {
+                            return GeneratorState::Yielded(last.replace(y).unwrap())
+                        }
+                        None => *last = Some(y),
+                    }
+                }
+                GeneratorState::Complete(r) => {
+                    match last.take() {
+                        Some(prev) => {
+                            *returned = Some(r);
+                            return GeneratorState::Yielded(prev);
+                        }
+                        None => return GeneratorState::Complete(r),
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<C, F> IntoIterator for DedupBy<C, F>
+where
+    Self: Coroutine<Resume = ()> + Unpin,
+    C: Coroutine,
+{
+    type Item = <Self as Coroutine>::Yield;
+    type IntoIter = IntoIter<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
+}
+
+type EqFn<T> = fn(&T, &T) -> bool;
+
+/// A coroutine which skips values which are equal to the value yielded just
+/// before them.
+///
+/// This is created by [`Coroutine::dedup`].
+pub struct Dedup<C: Coroutine> {
+    inner: DedupBy<C, EqFn<C::Yield>>,
+}
+
+impl<C: Coroutine> Dedup<C>
+where
+    C::Yield: PartialEq,
+{
+    pub(crate) fn new(inner: C) -> Self {
+        Self {
+            inner: DedupBy::new(inner, PartialEq::eq),
+        }
+    }
+}
+
+impl<C: Coroutine> Dedup<C> {
+    fn project(self: Pin<&mut Self>) -> Pin<&mut DedupBy<C, EqFn<C::Yield>>> {
+        // Safety: `inner` is structurally pinned, and it is not moved.
+        unsafe { self.map_unchecked_mut(|this| &mut this.inner) }
+    }
+}
+
+impl<C: Coroutine<Resume = ()>> Coroutine for Dedup<C> {
+    type Yield = C::Yield;
+    type Resume = ();
+    type Return = C::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        (): (),
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        self.project().resume_with(())
+    }
+}
+
+impl<C: Coroutine<Resume = ()> + Unpin> IntoIterator for Dedup<C> {
+    type Item = C::Yield;
+    type IntoIter = IntoIter<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, Generator, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    async fn produce(mut co: Co<i32>) -> &'static str {
+        co.yield_all(vec![1, 1, 2, 2, 2, 3, 1]).await;
+        "done"
+    }
+
+    #[test]
+    fn dedup() {
+        let xs: Vec<_> = Gen::new(produce).dedup().into_iter().collect();
+        assert_eq!(xs, [1, 2, 3, 1]);
+    }
+
+    #[test]
+    fn dedup_keeps_return() {
+        let mut gen = Gen::new(produce).dedup();
+        let mut gen = Pin::new(&mut gen);
+        for &x in &[1, 2, 3, 1] {
+            assert_eq!(gen.as_mut().resume(), GeneratorState::Yielded(x));
+        }
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Complete("done"));
+    }
+
+    #[test]
+    fn dedup_by() {
+        let gen = Gen::new(produce).dedup_by(|a, b| a % 2 == b % 2);
+        let xs: Vec<_> = gen.into_iter().collect();
+        assert_eq!(xs, [1, 2, 3]);
+    }
+
+    #[test]
+    fn empty() {
+        let mut gen = Gen::new(|_: Co<i32>| async { "done" }).dedup();
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Complete("done"));
+    }
+}
@@ -0,0 +1,85 @@
+use crate::ops::{Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which pairs each value yielded by another coroutine with its
+/// index.
+///
+/// This is created by [`Coroutine::enumerate`].
+pub struct Enumerate<C> {
+    inner: C,
+    count: usize,
+}
+
+impl<C> Enumerate<C> {
+    pub(crate) fn new(inner: C) -> Self {
+        Self { inner, count: 0 }
+    }
+
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut C>, &mut usize) {
+        // Safety: `inner` is structurally pinned, and `count` is not. Neither is
+        // moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (Pin::new_unchecked(&mut this.inner), &mut this.count)
+        }
+    }
+}
+
+impl<C: Coroutine> Coroutine for Enumerate<C> {
+    type Yield = (usize, C::Yield);
+    type Resume = C::Resume;
+    type Return = C::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (inner, count) = self.project();
+        match inner.resume_with(arg) {
+            GeneratorState::Yielded(y) => {
+                let index = *count;
+                *count += 1;
+                GeneratorState::Yielded((index, y))
+            }
+            GeneratorState::Complete(r) => GeneratorState::Complete(r),
+        }
+    }
+}
+
+impl_into_iterator!(Enumerate<C>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    async fn produce(mut co: Co<&'static str, &'static str>) -> &'static str {
+        let s = co.yield_("a").await;
+        co.yield_(s).await;
+        "done"
+    }
+
+    #[test]
+    fn counts_yields() {
+        let mut gen = Gen::new(produce).enumerate();
+        let mut resume = |arg| Pin::new(&mut gen).resume_with(arg);
+        assert_eq!(resume(""), GeneratorState::Yielded((0, "a")));
+        assert_eq!(resume("b"), GeneratorState::Yielded((1, "b")));
+        assert_eq!(resume(""), GeneratorState::Complete("done"));
+    }
+
+    #[test]
+    fn into_iter() {
+        let gen = Gen::new(|mut co: Co<char>| {
+            async move {
+                co.yield_('x').await;
+                co.yield_('y').await;
+            }
+        });
+        let xs: Vec<_> = gen.enumerate().into_iter().collect();
+        assert_eq!(xs, [(0, 'x'), (1, 'y')]);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::ops::{Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which only yields the values from another coroutine which match
+/// a predicate.
+///
+/// This is created by [`Coroutine::filter`].
+pub struct Filter<C, P> {
+    inner: C,
+    pred: P,
+}
+
+impl<C, P> Filter<C, P> {
+    pub(crate) fn new(inner: C, pred: P) -> Self {
+        Self { inner, pred }
+    }
+
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut C>, &mut P) {
+        // Safety: `inner` is structurally pinned, and `pred` is not. Neither is
+        // moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (Pin::new_unchecked(&mut this.inner), &mut this.pred)
+        }
+    }
+}
+
+impl<C, P> Coroutine for Filter<C, P>
+where
+    C: Coroutine,
+    C::Resume: Clone,
+    P: FnMut(&C::Yield) -> bool,
+{
+    type Yield = C::Yield;
+    type Resume = C::Resume;
+    type Return = C::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (mut inner, pred) = self.project();
+        loop {
+            match inner.as_mut().resume_with(arg.clone()) {
+                GeneratorState::Yielded(y) => {
+                    if pred(&y) {
+                        return GeneratorState::Yielded(y);
+                    }
+                }
+                GeneratorState::Complete(r) => return GeneratorState::Complete(r),
+            }
+        }
+    }
+}
+
+impl_into_iterator!(Filter<C, P>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    async fn produce(mut co: Co<i32>) -> &'static str {
+        for i in 0..10 {
+            co.yield_(i).await;
+        }
+        "done"
+    }
+
+    #[test]
+    fn keeps_passing_values() {
+        let xs: Vec<_> = Gen::new(produce)
+            .filter(|n| n % 2 == 0)
+            .into_iter()
+            .collect();
+        assert_eq!(xs, [0, 2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn passes_return_through() {
+        let mut gen = Gen::new(produce).filter(|&n| n >= 8);
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume_with(()), GeneratorState::Yielded(8));
+        assert_eq!(gen.as_mut().resume_with(()), GeneratorState::Yielded(9));
+        assert_eq!(
+            gen.as_mut().resume_with(()),
+            GeneratorState::Complete("done"),
+        );
+    }
+
+    #[test]
+    fn reuses_resume_argument() {
+        let gen = Gen::new(|mut co: Co<i32, i32>| {
+            async move {
+                let mut n = 0;
+                loop {
+                    n = co.yield_(n).await;
+                }
+            }
+        });
+        let mut gen = gen.filter(|&n| n != 0);
+        let mut gen = Pin::new(&mut gen);
+        // The first value, `0`, is skipped, so the inner coroutine is resumed a
+        // second time with another clone of `5`.
+        assert_eq!(gen.as_mut().resume_with(5), GeneratorState::Yielded(5));
+    }
+}
@@ -0,0 +1,121 @@
+use crate::ops::{adapters::IntoIter, Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which maps each value yielded by another coroutine to an
+/// iterator, and yields the items of those iterators.
+///
+/// This is created by [`Coroutine::flat_map`].
+pub struct FlatMap<C, G, I: IntoIterator> {
+    inner: C,
+    f: G,
+    current: Option<I::IntoIter>,
+}
+
+impl<C, G, I: IntoIterator> FlatMap<C, G, I> {
+    pub(crate) fn new(inner: C, f: G) -> Self {
+        Self {
+            inner,
+            f,
+            current: None,
+        }
+    }
+
+    fn project(
+        self: Pin<&mut Self>,
+    ) -> (Pin<&mut C>, &mut G, &mut Option<I::IntoIter>) {
+        // Safety: `inner` is structurally pinned, and the other fields are not.
+        // Nothing is moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (
+                Pin::new_unchecked(&mut this.inner),
+                &mut this.f,
+                &mut this.current,
+            )
+        }
+    }
+}
+
+// `f` and `current` are not structurally pinned.
+impl<C: Unpin, G, I: IntoIterator> Unpin for FlatMap<C, G, I> {}
+
+impl<C, G, I> Coroutine for FlatMap<C, G, I>
+where
+    C: Coroutine<Resume = ()>,
+    G: FnMut(C::Yield) -> I,
+    I: IntoIterator,
+{
+    type Yield = I::Item;
+    type Resume = ();
+    type Return = C::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        (): (),
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (mut inner, f, current) = self.project();
+        loop {
+            if let Some(iter) = current {
+                if let Some(item) = iter.next() {
+                    return GeneratorState::Yielded(item);
+                }
+                *current = None;
+            }
+            match inner.as_mut().resume_with(()) {
+                GeneratorState::Yielded(y) => *current = Some(f(y).into_iter()),
+                GeneratorState::Complete(r) => return GeneratorState::Complete(r),
+            }
+        }
+    }
+}
+
+impl<C, G, I> IntoIterator for FlatMap<C, G, I>
+where
+    Self: Coroutine<Resume = ()> + Unpin,
+    I: IntoIterator,
+{
+    type Item = <Self as Coroutine>::Yield;
+    type IntoIter = IntoIter<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, Generator, GeneratorState},
+        rc::Gen,
+    };
+    use std::pin::Pin;
+
+    #[test]
+    fn flat_map() {
+        let gen = Gen::from_iter(vec![1, 2]).flat_map(|x| vec![x, x * 10]);
+        let xs: Vec<_> = gen.into_iter().collect();
+        assert_eq!(xs, [1, 10, 2, 20]);
+    }
+
+    #[test]
+    fn skips_empty_iterators() {
+        let gen = Gen::from_iter(vec![0, 1, 0, 2]).flat_map(|x| 0..x);
+        let xs: Vec<_> = gen.into_iter().collect();
+        assert_eq!(xs, [0, 0, 1]);
+    }
+
+    #[test]
+    fn return_passes_through() {
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                co.yield_(2).await;
+                "done"
+            }
+        })
+        .flat_map(|x| vec![x; x]);
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Yielded(2));
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Yielded(2));
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Complete("done"));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::ops::{adapters::IntoIter, Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which runs each coroutine yielded by another coroutine, and
+/// yields their values.
+///
+/// This is created by [`Coroutine::flatten`].
+pub struct Flatten<C: Coroutine> {
+    outer: C,
+    inner: Option<C::Yield>,
+}
+
+impl<C: Coroutine> Flatten<C> {
+    pub(crate) fn new(outer: C) -> Self {
+        Self { outer, inner: None }
+    }
+
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut C>, Pin<&mut Option<C::Yield>>) {
+        // Safety: Both fields are structurally pinned. `inner` is only ever
+        // replaced through `Pin::set`, which drops the old value in place.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (
+                Pin::new_unchecked(&mut this.outer),
+                Pin::new_unchecked(&mut this.inner),
+            )
+        }
+    }
+}
+
+impl<C> Coroutine for Flatten<C>
+where
+    C: Coroutine,
+    C::Resume: Clone,
+    C::Yield: Coroutine<Resume = ()>,
+{
+    type Yield = <C::Yield as Coroutine>::Yield;
+    type Resume = C::Resume;
+    type Return = C::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (mut outer, mut inner) = self.project();
+        loop {
+            if let Some(current) = inner.as_mut().as_pin_mut() {
+                match current.resume_with(()) {
+                    GeneratorState::Yielded(y) => return GeneratorState::Yielded(y),
+                    GeneratorState::Complete(_) => inner.set(None),
+                }
+            }
+            match outer.as_mut().resume_with(arg.clone()) {
+                GeneratorState::Yielded(next) => inner.set(Some(next)),
+                GeneratorState::Complete(r) => return GeneratorState::Complete(r),
+            }
+        }
+    }
+}
+
+impl<C> IntoIterator for Flatten<C>
+where
+    Self: Coroutine<Resume = ()> + Unpin,
+    C: Coroutine,
+{
+    type Item = <Self as Coroutine>::Yield;
+    type IntoIter = IntoIter<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::{cell::RefCell, pin::Pin};
+
+    #[test]
+    fn yields_inner_values_in_order() {
+        let outer = Gen::new(|mut co| {
+            async move {
+                co.yield_(Gen::from_iter(vec![1, 2])).await;
+                co.yield_(Gen::from_iter(vec![])).await;
+                co.yield_(Gen::from_iter(vec![3])).await;
+                "done"
+            }
+        });
+        let mut gen = outer.flatten();
+        let mut gen = Pin::new(&mut gen);
+        for i in 1..=3 {
+            assert_eq!(gen.as_mut().resume_with(()), GeneratorState::Yielded(i));
+        }
+        assert_eq!(
+            gen.as_mut().resume_with(()),
+            GeneratorState::Complete("done"),
+        );
+    }
+
+    #[test]
+    fn resumes_one_inner_step_at_a_time() {
+        let log = &RefCell::new(Vec::new());
+        let inner = |name| {
+            Gen::new(move |mut co: Co<&'static str>| {
+                async move {
+                    log.borrow_mut().push(name);
+                    co.yield_(name).await;
+                    log.borrow_mut().push(name);
+                    co.yield_(name).await;
+                }
+            })
+        };
+        let outer = Gen::new(|mut co| {
+            async move {
+                co.yield_(inner("a")).await;
+                co.yield_(inner("b")).await;
+            }
+        });
+        let mut gen = outer.flatten().into_iter();
+        assert_eq!(gen.next(), Some("a"));
+        assert_eq!(*log.borrow(), ["a"]);
+        assert_eq!(gen.next(), Some("a"));
+        assert_eq!(gen.next(), Some("b"));
+        assert_eq!(*log.borrow(), ["a", "a", "b"]);
+        assert_eq!(gen.next(), Some("b"));
+        assert_eq!(gen.next(), None);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::ops::{Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which calls a function with a reference to each value yielded
+/// by another coroutine, before passing it on.
+///
+/// This is created by [`Coroutine::inspect`].
+pub struct Inspect<C, G> {
+    inner: C,
+    f: G,
+}
+
+impl<C, G> Inspect<C, G> {
+    pub(crate) fn new(inner: C, f: G) -> Self {
+        Self { inner, f }
+    }
+
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut C>, &mut G) {
+        // Safety: `inner` is structurally pinned, and `f` is not. Neither is
+        // moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (Pin::new_unchecked(&mut this.inner), &mut this.f)
+        }
+    }
+}
+
+impl<C, G> Coroutine for Inspect<C, G>
+where
+    C: Coroutine,
+    G: FnMut(&C::Yield),
+{
+    type Yield = C::Yield;
+    type Resume = C::Resume;
+    type Return = C::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (inner, f) = self.project();
+        let state = inner.resume_with(arg);
+        if let GeneratorState::Yielded(y) = &state {
+            f(y);
+        }
+        state
+    }
+}
+
+impl_into_iterator!(Inspect<C, G>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    #[test]
+    fn sees_each_value_once() {
+        let mut seen = Vec::new();
+        let gen = Gen::new(|mut co: Co<i32>| {
+            async move {
+                co.yield_(1).await;
+                co.yield_(2).await;
+                co.yield_(3).await;
+            }
+        });
+        let xs: Vec<_> = gen.inspect(|&n| seen.push(n)).into_iter().collect();
+        assert_eq!(xs, [1, 2, 3]);
+        assert_eq!(seen, [1, 2, 3]);
+    }
+
+    #[test]
+    fn skips_return() {
+        let mut calls = 0;
+        let gen = Gen::new(|mut co: Co<i32, i32>| {
+            async move {
+                let n = co.yield_(0).await;
+                n * 10
+            }
+        });
+        let mut gen = gen.inspect(|_| calls += 1);
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume_with(0), GeneratorState::Yielded(0));
+        assert_eq!(gen.as_mut().resume_with(4), GeneratorState::Complete(40));
+        assert_eq!(calls, 1);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::ops::{adapters::IntoIter, Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which yields a separator between each pair of values yielded by
+/// another coroutine.
+///
+/// This is created by [`Coroutine::intersperse`].
+pub struct Intersperse<C: Coroutine> {
+    inner: C,
+    sep: C::Yield,
+    next: Option<C::Yield>,
+    started: bool,
+}
+
+impl<C: Coroutine> Intersperse<C> {
+    pub(crate) fn new(inner: C, sep: C::Yield) -> Self {
+        Self {
+            inner,
+            sep,
+            next: None,
+            started: false,
+        }
+    }
+
+    fn project(
+        self: Pin<&mut Self>,
+    ) -> (Pin<&mut C>, &C::Yield, &mut Option<C::Yield>, &mut bool) {
+        // Safety: `inner` is structurally pinned, and the other fields are not.
+        // Nothing is moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (
+                Pin::new_unchecked(&mut this.inner),
+                &this.sep,
+                &mut this.next,
+                &mut this.started,
+            )
+        }
+    }
+}
+
+// `sep` and `next` are not structurally pinned.
+impl<C: Coroutine + Unpin> Unpin for Intersperse<C> {}
+
+impl<C> Coroutine for Intersperse<C>
+where
+    C: Coroutine<Resume = ()>,
+    C::Yield: Clone,
+{
+    type Yield = C::Yield;
+    type Resume = ();
+    type Return = C::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        (): (),
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (inner, sep, next, started) = self.project();
+        if let Some(value) = next.take() {
+            return GeneratorState::Yielded(value);
+        }
+        // The separator is only yielded once the value after it is known to
+        // exist, so there is never a trailing separator.
+        match inner.resume_with(()) {
+            GeneratorState::Yielded(y) if *started => {
+                *next = Some(y);
+                GeneratorState::Yielded(sep.clone())
+            }
+            GeneratorState::Yielded(y) => {
+                *started = true;
+                GeneratorState::Yielded(y)
+            }
+            GeneratorState::Complete(r) => GeneratorState::Complete(r),
+        }
+    }
+}
+
+impl<C> IntoIterator for Intersperse<C>
+where
+    Self: Coroutine<Resume = ()> + Unpin,
+    C: Coroutine,
+{
+    type Item = <Self as Coroutine>::Yield;
+    type IntoIter = IntoIter<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, Generator, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    #[test]
+    fn intersperse() {
+        let gen = Gen::from_iter(vec![1, 2, 3]).intersperse(0);
+        let xs: Vec<_> = gen.into_iter().collect();
+        assert_eq!(xs, [1, 0, 2, 0, 3]);
+    }
+
+    #[test]
+    fn single_value() {
+        let gen = Gen::from_iter(vec![1]).intersperse(0);
+        let xs: Vec<_> = gen.into_iter().collect();
+        assert_eq!(xs, [1]);
+    }
+
+    #[test]
+    fn empty() {
+        let mut gen = Gen::new(|_: Co<i32>| async { "done" }).intersperse(0);
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Complete("done"));
+    }
+
+    #[test]
+    fn return_passes_through() {
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                co.yield_(1).await;
+                co.yield_(2).await;
+                "done"
+            }
+        })
+        .intersperse(0);
+        let mut gen = Pin::new(&mut gen);
+        for &x in &[1, 0, 2] {
+            assert_eq!(gen.as_mut().resume(), GeneratorState::Yielded(x));
+        }
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Complete("done"));
+    }
+}
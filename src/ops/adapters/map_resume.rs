@@ -0,0 +1,79 @@
+use crate::ops::{Coroutine, GeneratorState};
+use core::{marker::PhantomData, pin::Pin};
+
+/// A coroutine which transforms the resume arguments passed to another
+/// coroutine.
+///
+/// This is created by [`Coroutine::map_resume`].
+pub struct MapResume<C, F, R> {
+    inner: C,
+    f: F,
+    _resume: PhantomData<fn(R)>,
+}
+
+impl<C, F, R> MapResume<C, F, R> {
+    pub(crate) fn new(inner: C, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            _resume: PhantomData,
+        }
+    }
+
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut C>, &mut F) {
+        // Safety: `inner` is structurally pinned, and `f` is not. Neither is moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (Pin::new_unchecked(&mut this.inner), &mut this.f)
+        }
+    }
+}
+
+impl<C: Coroutine, F: FnMut(R) -> C::Resume, R> Coroutine for MapResume<C, F, R> {
+    type Yield = C::Yield;
+    type Resume = R;
+    type Return = C::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (inner, f) = self.project();
+        inner.resume_with(f(arg))
+    }
+}
+
+impl_into_iterator!(MapResume<C, F, R>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    #[test]
+    fn maps_resume_args() {
+        async fn produce(mut co: Co<&'static str, usize>) -> usize {
+            let a = co.yield_("first").await;
+            let b = co.yield_("second").await;
+            a + b
+        }
+
+        let mut gen = Gen::new(produce).map_resume(|s: String| s.len());
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(
+            gen.as_mut().resume_with(String::new()),
+            GeneratorState::Yielded("first"),
+        );
+        assert_eq!(
+            gen.as_mut().resume_with("abc".to_string()),
+            GeneratorState::Yielded("second"),
+        );
+        assert_eq!(
+            gen.as_mut().resume_with("de".to_string()),
+            GeneratorState::Complete(5),
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use crate::ops::{Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which transforms the values yielded by another coroutine.
+///
+/// This is created by [`Coroutine::map_yield`].
+pub struct MapYield<C, F> {
+    inner: C,
+    f: F,
+}
+
+impl<C, F> MapYield<C, F> {
+    pub(crate) fn new(inner: C, f: F) -> Self {
+        Self { inner, f }
+    }
+
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut C>, &mut F) {
+        // Safety: `inner` is structurally pinned, and `f` is not. Neither is moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (Pin::new_unchecked(&mut this.inner), &mut this.f)
+        }
+    }
+}
+
+impl<C: Coroutine, Y, F: FnMut(C::Yield) -> Y> Coroutine for MapYield<C, F> {
+    type Yield = Y;
+    type Resume = C::Resume;
+    type Return = C::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (inner, f) = self.project();
+        match inner.resume_with(arg) {
+            GeneratorState::Yielded(y) => GeneratorState::Yielded(f(y)),
+            GeneratorState::Complete(r) => GeneratorState::Complete(r),
+        }
+    }
+}
+
+impl_into_iterator!(MapYield<C, F>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    async fn produce(mut co: Co<i32, i32>) -> &'static str {
+        let n = co.yield_(10).await;
+        co.yield_(n).await;
+        "done"
+    }
+
+    #[test]
+    fn maps_yields() {
+        let mut gen = Gen::new(produce).map_yield(|n| n.to_string());
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(
+            gen.as_mut().resume_with(0),
+            GeneratorState::Yielded("10".into())
+        );
+        assert_eq!(
+            gen.as_mut().resume_with(5),
+            GeneratorState::Yielded("5".into())
+        );
+    }
+
+    #[test]
+    fn passes_return_through() {
+        let mut gen = Gen::new(produce).map_yield(|n| n * 2);
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume_with(0), GeneratorState::Yielded(20));
+        assert_eq!(gen.as_mut().resume_with(0), GeneratorState::Yielded(0));
+        assert_eq!(
+            gen.as_mut().resume_with(0),
+            GeneratorState::Complete("done")
+        );
+    }
+
+    #[test]
+    fn into_iter() {
+        let gen = Gen::new(|mut co: Co<i32>| {
+            async move {
+                co.yield_(1).await;
+                co.yield_(2).await;
+            }
+        });
+        let xs: Vec<_> = gen.map_yield(|n| n + 1).into_iter().collect();
+        assert_eq!(xs, [2, 3]);
+    }
+}
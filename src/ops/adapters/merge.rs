@@ -0,0 +1,172 @@
+use crate::ops::{adapters::IntoIter, Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// Creates a coroutine which interleaves the values yielded by two coroutines.
+///
+/// The two coroutines take turns, starting with `a`. Once one of them
+/// completes, it is skipped, and the other one is resumed every time. The
+/// merged coroutine completes once both have completed, and returns both of
+/// their return values.
+///
+/// Unlike [`Coroutine::zip`], values are not paired up, and unlike
+/// [`Coroutine::chain`], neither coroutine has to finish before the other one
+/// starts.
+///
+/// ```rust
+/// use genawaiter::{adapters::merge, rc::Gen};
+///
+/// let odds = Gen::from_iter(vec![1, 3, 5]);
+/// let evens = Gen::from_iter(vec![2, 4]);
+/// let xs: Vec<_> = merge(odds, evens).into_iter().collect();
+/// assert_eq!(xs, [1, 2, 3, 4, 5]);
+/// ```
+pub fn merge<C1, C2>(a: C1, b: C2) -> Merge<C1, C2>
+where
+    C1: Coroutine<Resume = ()>,
+    C2: Coroutine<Yield = C1::Yield, Resume = ()>,
+{
+    Merge {
+        first: a,
+        second: b,
+        first_return: None,
+        second_return: None,
+        second_next: false,
+    }
+}
+
+/// A coroutine which interleaves the values yielded by two coroutines, taking
+/// turns between them.
+///
+/// This is created by [`merge`].
+pub struct Merge<C1: Coroutine, C2: Coroutine> {
+    first: C1,
+    second: C2,
+    first_return: Option<C1::Return>,
+    second_return: Option<C2::Return>,
+    second_next: bool,
+}
+
+impl<C1: Coroutine, C2: Coroutine> Merge<C1, C2> {
+    #[allow(clippy::type_complexity)]
+    fn project(
+        self: Pin<&mut Self>,
+    ) -> (
+        Pin<&mut C1>,
+        Pin<&mut C2>,
+        &mut Option<C1::Return>,
+        &mut Option<C2::Return>,
+        &mut bool,
+    ) {
+        // Safety: `first` and `second` are structurally pinned, and the other
+        // fields are not. Nothing is moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (
+                Pin::new_unchecked(&mut this.first),
+                Pin::new_unchecked(&mut this.second),
+                &mut this.first_return,
+                &mut this.second_return,
+                &mut this.second_next,
+            )
+        }
+    }
+}
+
+// The return values are not structurally pinned.
+impl<C1: Coroutine + Unpin, C2: Coroutine + Unpin> Unpin for Merge<C1, C2> {}
+
+impl<C1, C2> Coroutine for Merge<C1, C2>
+where
+    C1: Coroutine<Resume = ()>,
+    C2: Coroutine<Yield = C1::Yield, Resume = ()>,
+{
+    type Yield = C1::Yield;
+    type Resume = ();
+    type Return = (C1::Return, C2::Return);
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        (): (),
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (mut first, mut second, first_return, second_return, second_next) =
+            self.project();
+        loop {
+            // A coroutine which has completed is never resumed again.
+            let use_second =
+                second_return.is_none() && (*second_next || first_return.is_some());
+            *second_next = !use_second;
+            if use_second {
+                match second.as_mut().resume_with(()) {
+                    GeneratorState::Yielded(y) => return GeneratorState::Yielded(y),
+                    GeneratorState::Complete(r) => *second_return = Some(r),
+                }
+            } else {
+                match first.as_mut().resume_with(()) {
+                    GeneratorState::Yielded(y) => return GeneratorState::Yielded(y),
+                    GeneratorState::Complete(r) => *first_return = Some(r),
+                }
+            }
+            match (first_return.take(), second_return.take()) {
+                (Some(a), Some(b)) => return GeneratorState::Complete((a, b)),
+                (a, b) => {
+                    *first_return = a;
+                    *second_return = b;
+                }
+            }
+        }
+    }
+}
+
+impl<C1, C2> IntoIterator for Merge<C1, C2>
+where
+    Self: Coroutine<Resume = ()> + Unpin,
+    C1: Coroutine,
+    C2: Coroutine,
+{
+    type Item = <Self as Coroutine>::Yield;
+    type IntoIter = IntoIter<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{adapters::merge, Generator, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    #[test]
+    fn interleaves() {
+        let a = Gen::from_iter(vec![1, 3, 5]);
+        let b = Gen::from_iter(vec![2, 4]);
+        let xs: Vec<_> = merge(a, b).into_iter().collect();
+        assert_eq!(xs, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn skips_completed_side() {
+        let a = Gen::from_iter(vec![1]);
+        let b = Gen::from_iter(vec![2, 3, 4]);
+        let xs: Vec<_> = merge(a, b).into_iter().collect();
+        assert_eq!(xs, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn returns_both() {
+        let a = Gen::new(|mut co: Co<i32>| {
+            async move {
+                co.yield_(1).await;
+                "a"
+            }
+        });
+        let b = Gen::new(|_: Co<i32>| async { "b" });
+        let mut gen = merge(a, b);
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Yielded(1));
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Complete(("a", "b")));
+    }
+}
@@ -0,0 +1,144 @@
+/*!
+This module contains adapters which wrap a coroutine to change its behavior.
+
+Adapters are created by the methods on [`Coroutine`](crate::Coroutine). They
+are coroutines themselves, so they can be chained, and resume arguments are threaded
+through them to the coroutine inside.
+
+```rust
+use genawaiter::{rc::Gen, Coroutine, GeneratorState};
+use std::pin::Pin;
+
+let gen = Gen::new(|mut co| async move {
+    co.yield_(1).await;
+    co.yield_(2).await;
+});
+let mut doubled = gen.map_yield(|n| n * 2);
+assert_eq!(Pin::new(&mut doubled).resume_with(()), GeneratorState::Yielded(2));
+assert_eq!(Pin::new(&mut doubled).resume_with(()), GeneratorState::Yielded(4));
+assert_eq!(Pin::new(&mut doubled).resume_with(()), GeneratorState::Complete(()));
+```
+
+If the resume type is `()`, adapters can also be used as iterators:
+
+```rust
+# use genawaiter::{rc::Gen, Coroutine};
+#
+# let gen = Gen::new(|mut co| async move {
+#     co.yield_(1).await;
+#     co.yield_(2).await;
+# });
+let doubled: Vec<_> = gen.map_yield(|n| n * 2).into_iter().collect();
+assert_eq!(doubled, [2, 4]);
+```
+*/
+
+use crate::ops::{Coroutine, Generator, GeneratorState};
+use core::{iter::FusedIterator, pin::Pin};
+
+pub use crate::ops::adapters::{
+    chain::Chain,
+    chunks::Chunks,
+    cloned::Cloned,
+    copied::Copied,
+    dedup::{Dedup, DedupBy},
+    enumerate::Enumerate,
+    filter::Filter,
+    flat_map::FlatMap,
+    flatten::Flatten,
+    inspect::Inspect,
+    intersperse::Intersperse,
+    map_resume::MapResume,
+    map_yield::MapYield,
+    merge::{merge, Merge},
+    observe::{Event, Observe},
+    peekable::Peekable,
+    race::{Either, Race},
+    retaining::Retaining,
+    scan::Scan,
+    skip::Skip,
+    step_by::StepBy,
+    take::Take,
+    windows::Windows,
+    with_return::WithReturn,
+    zip::Zip,
+};
+
+/// Implements `IntoIterator` for an adapter, using [`IntoIter`].
+macro_rules! impl_into_iterator {
+    ($name:ident<$($param:ident),*>) => {
+        impl<$($param),*> IntoIterator for $name<$($param),*>
+        where
+            Self: $crate::ops::Coroutine<Resume = ()> + Unpin,
+        {
+            type Item = <Self as $crate::ops::Coroutine>::Yield;
+            type IntoIter = $crate::ops::adapters::IntoIter<Self>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                $crate::ops::adapters::IntoIter::new(self)
+            }
+        }
+    };
+}
+
+mod chain;
+mod chunks;
+mod cloned;
+mod copied;
+mod dedup;
+mod enumerate;
+mod filter;
+mod flat_map;
+mod flatten;
+mod inspect;
+mod intersperse;
+mod map_resume;
+mod map_yield;
+mod merge;
+mod observe;
+mod peekable;
+mod race;
+mod retaining;
+mod scan;
+mod skip;
+mod step_by;
+mod take;
+mod windows;
+mod with_return;
+mod zip;
+
+/// An iterator over the values yielded by an adapter.
+///
+/// The return value of the coroutine is discarded.
+pub struct IntoIter<C> {
+    coroutine: C,
+    done: bool,
+}
+
+impl<C> IntoIter<C> {
+    fn new(coroutine: C) -> Self {
+        Self {
+            coroutine,
+            done: false,
+        }
+    }
+}
+
+impl<C: Coroutine<Resume = ()> + Unpin> Iterator for IntoIter<C> {
+    type Item = C::Yield;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match Pin::new(&mut self.coroutine).resume() {
+            GeneratorState::Yielded(x) => Some(x),
+            GeneratorState::Complete(_) => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+impl<C: Coroutine<Resume = ()> + Unpin> FusedIterator for IntoIter<C> {}
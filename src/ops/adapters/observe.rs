@@ -0,0 +1,124 @@
+use crate::ops::{Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// Something which happened to a coroutine wrapped by [`Observe`].
+#[derive(PartialEq, Eq, Debug)]
+pub enum Event<'a, Y, R, Ret> {
+    /// The coroutine is about to be resumed with this argument.
+    ResumeCalled(&'a R),
+
+    /// The coroutine yielded this value.
+    Yielded(&'a Y),
+
+    /// The coroutine completed with this value.
+    Completed(&'a Ret),
+}
+
+/// A coroutine which calls a function on each resume argument, yielded value,
+/// and return value of another coroutine.
+///
+/// This is created by [`Coroutine::observe`].
+pub struct Observe<C, G> {
+    inner: C,
+    f: G,
+}
+
+impl<C, G> Observe<C, G> {
+    pub(crate) fn new(inner: C, f: G) -> Self {
+        Self { inner, f }
+    }
+
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut C>, &mut G) {
+        // Safety: `inner` is structurally pinned, and `f` is not. Neither is
+        // moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (Pin::new_unchecked(&mut this.inner), &mut this.f)
+        }
+    }
+}
+
+impl<C, G> Coroutine for Observe<C, G>
+where
+    C: Coroutine,
+    G: FnMut(&Event<'_, C::Yield, C::Resume, C::Return>),
+{
+    type Yield = C::Yield;
+    type Resume = C::Resume;
+    type Return = C::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (inner, f) = self.project();
+        f(&Event::ResumeCalled(&arg));
+        let state = inner.resume_with(arg);
+        match &state {
+            GeneratorState::Yielded(y) => f(&Event::Yielded(y)),
+            GeneratorState::Complete(r) => f(&Event::Completed(r)),
+        }
+        state
+    }
+}
+
+impl_into_iterator!(Observe<C, G>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{adapters::Event, Coroutine, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    #[test]
+    fn event_sequence() {
+        let mut log = Vec::new();
+        let gen = Gen::new(|mut co: Co<i32, i32>| {
+            async move {
+                let n = co.yield_(1).await;
+                let m = co.yield_(n + 1).await;
+                n * m
+            }
+        });
+        let mut gen = gen.observe(|event| {
+            log.push(match *event {
+                Event::ResumeCalled(&r) => ("resume", r),
+                Event::Yielded(&y) => ("yield", y),
+                Event::Completed(&r) => ("complete", r),
+            });
+        });
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume_with(0), GeneratorState::Yielded(1));
+        assert_eq!(gen.as_mut().resume_with(2), GeneratorState::Yielded(3));
+        assert_eq!(gen.as_mut().resume_with(5), GeneratorState::Complete(10));
+        assert_eq!(log, [
+            ("resume", 0),
+            ("yield", 1),
+            ("resume", 2),
+            ("yield", 3),
+            ("resume", 5),
+            ("complete", 10),
+        ]);
+    }
+
+    #[test]
+    fn match_events() {
+        let mut completed = None;
+        let gen = Gen::new(|mut co: Co<i32>| {
+            async move {
+                co.yield_(1).await;
+                "done"
+            }
+        });
+        let gen = gen.observe(|event| {
+            if let Event::Completed(&r) = event {
+                completed = Some(r);
+            }
+        });
+        let xs: Vec<_> = gen.into_iter().collect();
+        assert_eq!(xs, [1]);
+        assert_eq!(completed, Some("done"));
+    }
+}
@@ -0,0 +1,230 @@
+use crate::ops::{adapters::IntoIter, Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which can look at the next value from another coroutine without
+/// consuming it.
+///
+/// This is created by [`Coroutine::peekable`].
+pub struct Peekable<C: Coroutine> {
+    inner: C,
+    peeked: Option<GeneratorState<C::Yield, C::Return>>,
+    done: bool,
+}
+
+impl<C: Coroutine> Peekable<C> {
+    pub(crate) fn new(inner: C) -> Self {
+        Self {
+            inner,
+            peeked: None,
+            done: false,
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn project(
+        self: Pin<&mut Self>,
+    ) -> (
+        Pin<&mut C>,
+        &mut Option<GeneratorState<C::Yield, C::Return>>,
+        &mut bool,
+    ) {
+        // Safety: `inner` is structurally pinned, and `peeked` and `done` are not.
+        // None of them is moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (
+                Pin::new_unchecked(&mut this.inner),
+                &mut this.peeked,
+                &mut this.done,
+            )
+        }
+    }
+
+    /// Consumes the coroutine, and returns its return value, if it completed
+    /// during a call to [`peek`](#method.peek).
+    ///
+    /// Returns `None` if the coroutine has not completed yet, or if its return
+    /// value was already returned by a resume.
+    pub fn into_return(self) -> Option<C::Return> {
+        match self.peeked {
+            Some(GeneratorState::Complete(value)) => Some(value),
+            Some(GeneratorState::Yielded(_)) | None => None,
+        }
+    }
+}
+
+impl<C: Coroutine<Resume = ()> + Unpin> Peekable<C> {
+    /// Returns a reference to the next yielded value, without consuming it.
+    ///
+    /// This resumes the coroutine at most once. The value is stored, and is
+    /// returned again by the next resume. Returns `None` if the coroutine
+    /// completed.
+    pub fn peek(&mut self) -> Option<&C::Yield> {
+        if self.peeked.is_none() && !self.done {
+            let state = Pin::new(&mut self.inner).resume_with(());
+            if let GeneratorState::Complete(_) = state {
+                self.done = true;
+            }
+            self.peeked = Some(state);
+        }
+        match &self.peeked {
+            Some(GeneratorState::Yielded(value)) => Some(value),
+            Some(GeneratorState::Complete(_)) | None => None,
+        }
+    }
+
+    /// Consumes and returns the next yielded value, if `pred` returns `true`
+    /// for it.
+    ///
+    /// Otherwise, the value is kept, and is returned again by the next peek or
+    /// resume. Returns `None` if the coroutine completed.
+    pub fn next_if(
+        &mut self,
+        pred: impl FnOnce(&C::Yield) -> bool,
+    ) -> Option<C::Yield> {
+        let matches = match self.peek() {
+            Some(value) => pred(value),
+            None => false,
+        };
+        if !matches {
+            return None;
+        }
+        match self.peeked.take() {
+            Some(GeneratorState::Yielded(value)) => Some(value),
+            // `peek` returned a value, so one is stored.
+            Some(GeneratorState::Complete(_)) | None => unreachable!(),
+        }
+    }
+
+    /// Consumes and returns the next yielded value, if it is equal to
+    /// `expected`.
+    ///
+    /// This works like [`next_if`](#method.next_if).
+    pub fn next_if_eq<T>(&mut self, expected: &T) -> Option<C::Yield>
+    where
+        T: ?Sized,
+        C::Yield: PartialEq<T>,
+    {
+        self.next_if(|value| value == expected)
+    }
+}
+
+// `peeked` and `done` are not structurally pinned.
+impl<C: Coroutine + Unpin> Unpin for Peekable<C> {}
+
+impl<C: Coroutine<Resume = ()>> Coroutine for Peekable<C> {
+    type Yield = C::Yield;
+    type Resume = ();
+    type Return = C::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: (),
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (inner, peeked, done) = self.project();
+        let state = match peeked.take() {
+            Some(state) => state,
+            None => inner.resume_with(arg),
+        };
+        if let GeneratorState::Complete(_) = state {
+            *done = true;
+        }
+        state
+    }
+}
+
+impl<C: Coroutine<Resume = ()> + Unpin> IntoIterator for Peekable<C> {
+    type Item = C::Yield;
+    type IntoIter = IntoIter<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, Generator, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    async fn produce(mut co: Co<i32>) -> &'static str {
+        co.yield_(1).await;
+        co.yield_(2).await;
+        "done"
+    }
+
+    #[test]
+    fn peek_then_consume() {
+        let mut gen = Gen::new(produce).peekable();
+        assert_eq!(gen.peek(), Some(&1));
+        assert_eq!(gen.peek(), Some(&1));
+        assert_eq!(Pin::new(&mut gen).resume(), GeneratorState::Yielded(1));
+        assert_eq!(Pin::new(&mut gen).resume(), GeneratorState::Yielded(2));
+        assert_eq!(gen.peek(), None);
+        assert_eq!(gen.peek(), None);
+        assert_eq!(gen.into_return(), Some("done"));
+    }
+
+    #[test]
+    fn resume_returns_peeked_completion() {
+        let mut gen = Gen::new(produce).peekable();
+        assert_eq!(Pin::new(&mut gen).resume(), GeneratorState::Yielded(1));
+        assert_eq!(Pin::new(&mut gen).resume(), GeneratorState::Yielded(2));
+        assert_eq!(gen.peek(), None);
+        assert_eq!(
+            Pin::new(&mut gen).resume(),
+            GeneratorState::Complete("done"),
+        );
+        assert_eq!(gen.into_return(), None);
+    }
+
+    #[test]
+    fn peek_after_exhausted_by_resume() {
+        let mut gen = Gen::new(produce).peekable();
+        assert_eq!(Pin::new(&mut gen).resume(), GeneratorState::Yielded(1));
+        assert_eq!(Pin::new(&mut gen).resume(), GeneratorState::Yielded(2));
+        assert_eq!(
+            Pin::new(&mut gen).resume(),
+            GeneratorState::Complete("done"),
+        );
+        // The coroutine is not resumed again, which would panic.
+        assert_eq!(gen.peek(), None);
+        assert_eq!(gen.peek(), None);
+        assert_eq!(gen.into_return(), None);
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut gen = Gen::new(produce).peekable();
+        assert_eq!(gen.peek(), Some(&1));
+        let xs: Vec<_> = gen.into_iter().collect();
+        assert_eq!(xs, [1, 2]);
+    }
+
+    #[test]
+    fn next_if() {
+        let mut gen = Gen::new(produce).peekable();
+        assert_eq!(gen.next_if(|&n| n > 1), None);
+        assert_eq!(gen.next_if(|&n| n == 1), Some(1));
+        assert_eq!(gen.peek(), Some(&2));
+        assert_eq!(gen.next_if(|_| true), Some(2));
+        assert_eq!(gen.next_if(|_| true), None);
+        assert_eq!(gen.into_return(), Some("done"));
+    }
+
+    #[test]
+    fn next_if_eq() {
+        let mut gen = Gen::new(produce).peekable();
+        assert_eq!(gen.next_if_eq(&2), None);
+        assert_eq!(gen.next_if_eq(&1), Some(1));
+        assert_eq!(gen.next_if_eq(&2), Some(2));
+        assert_eq!(gen.next_if_eq(&3), None);
+        assert_eq!(
+            Pin::new(&mut gen).resume(),
+            GeneratorState::Complete("done"),
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use crate::ops::{adapters::IntoIter, Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A value which came from one of two coroutines.
+///
+/// This is yielded by [`Race`].
+#[derive(PartialEq, Eq, Debug)]
+pub enum Either<L, R> {
+    /// The value came from the first coroutine.
+    Left(L),
+
+    /// The value came from the second coroutine.
+    Right(R),
+}
+
+/// A coroutine which advances whichever of two coroutines is ready, preferring
+/// the first.
+///
+/// This is created by [`Coroutine::race`].
+pub struct Race<C1: Coroutine, C2> {
+    left: Option<C1>,
+    left_return: Option<C1::Return>,
+    right: C2,
+}
+
+impl<C1: Coroutine, C2> Race<C1, C2> {
+    pub(crate) fn new(left: C1, right: C2) -> Self {
+        Self {
+            left: Some(left),
+            left_return: None,
+            right,
+        }
+    }
+
+    fn project(
+        self: Pin<&mut Self>,
+    ) -> (Pin<&mut Option<C1>>, &mut Option<C1::Return>, Pin<&mut C2>) {
+        // Safety: `left` and `right` are structurally pinned, and `left_return`
+        // is not. `left` is only ever cleared through `Pin::set`, which drops it
+        // in place.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (
+                Pin::new_unchecked(&mut this.left),
+                &mut this.left_return,
+                Pin::new_unchecked(&mut this.right),
+            )
+        }
+    }
+}
+
+// `left_return` is not structurally pinned.
+impl<C1: Coroutine + Unpin, C2: Unpin> Unpin for Race<C1, C2> {}
+
+impl<C1, C2> Coroutine for Race<C1, C2>
+where
+    C1: Coroutine,
+    C1::Resume: Default,
+    C2: Coroutine<Resume = C1::Resume>,
+{
+    type Yield = Either<C1::Yield, C2::Yield>;
+    type Resume = C1::Resume;
+    type Return = (C1::Return, C2::Return);
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (mut left, left_return, right) = self.project();
+        let arg = match left.as_mut().as_pin_mut() {
+            None => arg,
+            Some(inner) => {
+                match inner.resume_with(arg) {
+                    GeneratorState::Yielded(y) => {
+                        return GeneratorState::Yielded(Either::Left(y));
+                    }
+                    GeneratorState::Complete(r) => {
+                        *left_return = Some(r);
+                        left.set(None);
+                    }
+                }
+                // As with `Chain`, the argument was used up by the first
+                // coroutine, and the second one never observes its first resume
+                // argument anyway.
+                Default::default()
+            }
+        };
+        match right.resume_with(arg) {
+            GeneratorState::Yielded(y) => GeneratorState::Yielded(Either::Right(y)),
+            GeneratorState::Complete(r) => {
+                match left_return.take() {
+                    Some(l) => GeneratorState::Complete((l, r)),
+                    // `left_return` is set when `left` completes, and only taken
+                    // here, once `right` completes too.
+                    None => unreachable!(),
+                }
+            }
+        }
+    }
+}
+
+impl<C1, C2> IntoIterator for Race<C1, C2>
+where
+    Self: Coroutine<Resume = ()> + Unpin,
+    C1: Coroutine,
+{
+    type Item = <Self as Coroutine>::Yield;
+    type IntoIter = IntoIter<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{adapters::Either, Coroutine, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    #[test]
+    fn prefers_left() {
+        let left = Gen::new(|mut co: Co<i32>| {
+            async move {
+                co.yield_(1).await;
+                co.yield_(2).await;
+                co.yield_(3).await;
+                "left"
+            }
+        });
+        let right = Gen::new(|mut co: Co<char>| {
+            async move {
+                co.yield_('a').await;
+                "right"
+            }
+        });
+        let mut gen = left.race(right);
+        let mut gen = Pin::new(&mut gen);
+        for &n in &[1, 2, 3] {
+            assert_eq!(
+                gen.as_mut().resume_with(()),
+                GeneratorState::Yielded(Either::Left(n)),
+            );
+        }
+        assert_eq!(
+            gen.as_mut().resume_with(()),
+            GeneratorState::Yielded(Either::Right('a')),
+        );
+        assert_eq!(
+            gen.as_mut().resume_with(()),
+            GeneratorState::Complete(("left", "right")),
+        );
+    }
+
+    #[test]
+    fn into_iter() {
+        let left = Gen::new(|mut co: Co<i32>| {
+            async move {
+                co.yield_(1).await;
+            }
+        });
+        let right = Gen::new(|mut co: Co<i32>| {
+            async move {
+                co.yield_(10).await;
+                co.yield_(20).await;
+            }
+        });
+        let xs: Vec<_> = left.race(right).into_iter().collect();
+        assert_eq!(xs, [Either::Left(1), Either::Right(10), Either::Right(20)]);
+    }
+}
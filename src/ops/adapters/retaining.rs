@@ -0,0 +1,121 @@
+use crate::ops::{adapters::IntoIter, CompletedCoroutine, Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which keeps the return value of another coroutine, instead of
+/// handing it out.
+///
+/// This is created by [`Coroutine::retaining`].
+pub struct Retaining<C: Coroutine> {
+    inner: C,
+    returned: Option<C::Return>,
+}
+
+impl<C: Coroutine> Retaining<C> {
+    pub(crate) fn new(inner: C) -> Self {
+        Self {
+            inner,
+            returned: None,
+        }
+    }
+
+    /// Returns the value the coroutine completed with, or `None` if it has not
+    /// completed yet.
+    pub fn into_return(self) -> Option<C::Return> {
+        self.returned
+    }
+
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut C>, &mut Option<C::Return>) {
+        // Safety: `inner` is structurally pinned, and `returned` is not. Neither
+        // is moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (Pin::new_unchecked(&mut this.inner), &mut this.returned)
+        }
+    }
+}
+
+// `returned` is not structurally pinned.
+impl<C: Coroutine + Unpin> Unpin for Retaining<C> {}
+
+impl<C: Coroutine> Coroutine for Retaining<C> {
+    type Yield = C::Yield;
+    type Resume = C::Resume;
+    type Return = ();
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (inner, returned) = self.project();
+        // Once the coroutine has completed, it is never resumed again.
+        if returned.is_some() {
+            return GeneratorState::Complete(());
+        }
+        match inner.resume_with(arg) {
+            GeneratorState::Yielded(y) => GeneratorState::Yielded(y),
+            GeneratorState::Complete(r) => {
+                *returned = Some(r);
+                GeneratorState::Complete(())
+            }
+        }
+    }
+}
+
+impl<C: Coroutine> CompletedCoroutine for Retaining<C> {
+    type Retained = C::Return;
+
+    fn return_ref(&self) -> Option<&Self::Retained> {
+        self.returned.as_ref()
+    }
+}
+
+impl<C> IntoIterator for Retaining<C>
+where
+    Self: Coroutine<Resume = ()> + Unpin,
+    C: Coroutine,
+{
+    type Item = <Self as Coroutine>::Yield;
+    type IntoIter = IntoIter<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{CompletedCoroutine, Coroutine, Generator, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    async fn produce(mut co: Co<i32>) -> String {
+        co.yield_(1).await;
+        co.yield_(2).await;
+        "done".to_string()
+    }
+
+    #[test]
+    fn return_is_borrowable_repeatedly() {
+        let mut gen = Gen::new(produce).retaining();
+        let mut pinned = Pin::new(&mut gen);
+        assert_eq!(pinned.as_mut().resume(), GeneratorState::Yielded(1));
+        assert_eq!(pinned.return_ref(), None);
+        assert_eq!(pinned.as_mut().resume(), GeneratorState::Yielded(2));
+        assert_eq!(pinned.as_mut().resume(), GeneratorState::Complete(()));
+
+        assert_eq!(gen.return_ref().map(String::as_str), Some("done"));
+        assert_eq!(gen.return_ref().map(String::as_str), Some("done"));
+        assert_eq!(gen.into_return(), Some("done".to_string()));
+    }
+
+    #[test]
+    fn stays_complete() {
+        let mut gen = Gen::new(produce).retaining();
+        let mut gen = Pin::new(&mut gen);
+        while let GeneratorState::Yielded(_) = gen.as_mut().resume() {}
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Complete(()));
+        assert_eq!(gen.return_ref().map(String::as_str), Some("done"));
+    }
+}
@@ -0,0 +1,137 @@
+use crate::ops::{Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which transforms the values yielded by another coroutine, while
+/// threading some state through.
+///
+/// This is created by [`Coroutine::scan`].
+pub struct Scan<C, St, F> {
+    inner: C,
+    state: St,
+    f: F,
+    done: bool,
+}
+
+impl<C, St, F> Scan<C, St, F> {
+    pub(crate) fn new(inner: C, state: St, f: F) -> Self {
+        Self {
+            inner,
+            state,
+            f,
+            done: false,
+        }
+    }
+
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut C>, &mut St, &mut F, &mut bool) {
+        // Safety: `inner` is structurally pinned, and the other fields are not.
+        // None of them are moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (
+                Pin::new_unchecked(&mut this.inner),
+                &mut this.state,
+                &mut this.f,
+                &mut this.done,
+            )
+        }
+    }
+}
+
+impl<C, St, Out, F> Coroutine for Scan<C, St, F>
+where
+    C: Coroutine,
+    F: FnMut(&mut St, C::Yield) -> Option<Out>,
+{
+    type Yield = Out;
+    type Resume = C::Resume;
+    type Return = Option<C::Return>;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (inner, state, f, done) = self.project();
+        if *done {
+            return GeneratorState::Complete(None);
+        }
+        match inner.resume_with(arg) {
+            GeneratorState::Yielded(y) => {
+                if let Some(out) = f(state, y) {
+                    GeneratorState::Yielded(out)
+                } else {
+                    *done = true;
+                    GeneratorState::Complete(None)
+                }
+            }
+            GeneratorState::Complete(r) => {
+                *done = true;
+                GeneratorState::Complete(Some(r))
+            }
+        }
+    }
+}
+
+impl_into_iterator!(Scan<C, St, F>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    async fn produce(mut co: Co<i32, i32>) -> &'static str {
+        let mut n = 1;
+        for _ in 0..3 {
+            n = co.yield_(n).await;
+        }
+        "done"
+    }
+
+    #[test]
+    fn running_total() {
+        let mut gen = Gen::new(produce).scan(0, |total, n| {
+            *total += n;
+            Some(*total)
+        });
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume_with(0), GeneratorState::Yielded(1));
+        assert_eq!(gen.as_mut().resume_with(2), GeneratorState::Yielded(3));
+        assert_eq!(gen.as_mut().resume_with(3), GeneratorState::Yielded(6));
+        assert_eq!(
+            gen.as_mut().resume_with(0),
+            GeneratorState::Complete(Some("done")),
+        );
+    }
+
+    #[test]
+    fn stops_when_f_returns_none() {
+        let mut gen =
+            Gen::new(produce).scan((), |(), n| if n < 5 { Some(n) } else { None });
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume_with(0), GeneratorState::Yielded(1));
+        assert_eq!(gen.as_mut().resume_with(2), GeneratorState::Yielded(2));
+        assert_eq!(gen.as_mut().resume_with(9), GeneratorState::Complete(None));
+        assert_eq!(gen.as_mut().resume_with(0), GeneratorState::Complete(None));
+    }
+
+    #[test]
+    fn into_iter() {
+        let gen = Gen::new(|mut co: Co<i32>| {
+            async move {
+                for i in 1..=4 {
+                    co.yield_(i).await;
+                }
+            }
+        });
+        let xs: Vec<_> = gen
+            .scan(1, |product, n| {
+                *product *= n;
+                Some(*product)
+            })
+            .into_iter()
+            .collect();
+        assert_eq!(xs, [1, 2, 6, 24]);
+    }
+}
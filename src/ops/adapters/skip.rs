@@ -0,0 +1,112 @@
+use crate::ops::{Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which discards the first few values yielded by another
+/// coroutine.
+///
+/// This is created by [`Coroutine::skip`].
+pub struct Skip<C> {
+    inner: C,
+    remaining: usize,
+}
+
+impl<C> Skip<C> {
+    pub(crate) fn new(inner: C, n: usize) -> Self {
+        Self {
+            inner,
+            remaining: n,
+        }
+    }
+
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut C>, &mut usize) {
+        // Safety: `inner` is structurally pinned, and `remaining` is not. Neither
+        // is moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (Pin::new_unchecked(&mut this.inner), &mut this.remaining)
+        }
+    }
+}
+
+impl<C> Coroutine for Skip<C>
+where
+    C: Coroutine,
+    C::Resume: Clone,
+{
+    type Yield = C::Yield;
+    type Resume = C::Resume;
+    type Return = C::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (mut inner, remaining) = self.project();
+        while *remaining > 0 {
+            match inner.as_mut().resume_with(arg.clone()) {
+                GeneratorState::Yielded(_) => *remaining -= 1,
+                GeneratorState::Complete(r) => {
+                    *remaining = 0;
+                    return GeneratorState::Complete(r);
+                }
+            }
+        }
+        inner.resume_with(arg)
+    }
+}
+
+impl_into_iterator!(Skip<C>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    async fn produce(mut co: Co<i32, i32>) -> &'static str {
+        let mut n = 0;
+        for _ in 0..3 {
+            n = co.yield_(n).await;
+        }
+        "done"
+    }
+
+    #[test]
+    fn skips_first_n() {
+        let mut gen = Gen::new(produce).skip(1);
+        let mut gen = Pin::new(&mut gen);
+        // The inner coroutine is resumed with `7` twice: once for the skipped
+        // value, and once for the value which is surfaced.
+        assert_eq!(gen.as_mut().resume_with(7), GeneratorState::Yielded(7));
+        assert_eq!(gen.as_mut().resume_with(8), GeneratorState::Yielded(8));
+        assert_eq!(
+            gen.as_mut().resume_with(9),
+            GeneratorState::Complete("done")
+        );
+    }
+
+    #[test]
+    fn completes_while_skipping() {
+        let mut gen = Gen::new(produce).skip(5);
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(
+            gen.as_mut().resume_with(0),
+            GeneratorState::Complete("done")
+        );
+    }
+
+    #[test]
+    fn into_iter() {
+        let gen = Gen::new(|mut co: Co<i32>| {
+            async move {
+                for i in 0..4 {
+                    co.yield_(i).await;
+                }
+            }
+        });
+        let xs: Vec<_> = gen.skip(2).into_iter().collect();
+        assert_eq!(xs, [2, 3]);
+    }
+}
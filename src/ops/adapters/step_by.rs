@@ -0,0 +1,114 @@
+use crate::ops::{Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which yields every `n`-th value yielded by another coroutine,
+/// starting with the first.
+///
+/// This is created by [`Coroutine::step_by`].
+pub struct StepBy<C> {
+    inner: C,
+    skip: usize,
+    first: bool,
+}
+
+impl<C> StepBy<C> {
+    pub(crate) fn new(inner: C, step: usize) -> Self {
+        assert!(step != 0, "`step_by` was called with a step of 0");
+        Self {
+            inner,
+            skip: step - 1,
+            first: true,
+        }
+    }
+
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut C>, usize, &mut bool) {
+        // Safety: `inner` is structurally pinned, and the other fields are not.
+        // Nothing is moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (
+                Pin::new_unchecked(&mut this.inner),
+                this.skip,
+                &mut this.first,
+            )
+        }
+    }
+}
+
+impl<C> Coroutine for StepBy<C>
+where
+    C: Coroutine,
+    C::Resume: Clone,
+{
+    type Yield = C::Yield;
+    type Resume = C::Resume;
+    type Return = C::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (mut inner, skip, first) = self.project();
+        if !*first {
+            for _ in 0..skip {
+                if let GeneratorState::Complete(r) =
+                    inner.as_mut().resume_with(arg.clone())
+                {
+                    return GeneratorState::Complete(r);
+                }
+            }
+        }
+        *first = false;
+        inner.resume_with(arg)
+    }
+}
+
+impl_into_iterator!(StepBy<C>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    async fn produce(mut co: Co<i32>) -> &'static str {
+        for i in 0..20 {
+            co.yield_(i).await;
+        }
+        "done"
+    }
+
+    #[test]
+    fn yields_every_nth_value() {
+        let xs: Vec<_> = Gen::new(produce).step_by(3).into_iter().collect();
+        assert_eq!(xs, [0, 3, 6, 9, 12, 15, 18]);
+    }
+
+    #[test]
+    fn passes_return_through_while_skipping() {
+        let mut gen = Gen::new(produce).step_by(3);
+        let mut gen = Pin::new(&mut gen);
+        for i in (0..20).step_by(3) {
+            assert_eq!(gen.as_mut().resume_with(()), GeneratorState::Yielded(i));
+        }
+        // Only `19` is left to skip when the inner coroutine completes.
+        assert_eq!(
+            gen.as_mut().resume_with(()),
+            GeneratorState::Complete("done"),
+        );
+    }
+
+    #[test]
+    fn step_of_one_yields_everything() {
+        let xs: Vec<_> = Gen::new(produce).step_by(1).into_iter().collect();
+        assert_eq!(xs, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "`step_by` was called with a step of 0")]
+    fn step_of_zero_panics() {
+        let _ = Gen::new(produce).step_by(0);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::ops::{Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which yields at most a given number of values from another
+/// coroutine.
+///
+/// This is created by [`Coroutine::take`].
+pub struct Take<C> {
+    inner: C,
+    remaining: usize,
+}
+
+impl<C> Take<C> {
+    pub(crate) fn new(inner: C, n: usize) -> Self {
+        Self {
+            inner,
+            remaining: n,
+        }
+    }
+
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut C>, &mut usize) {
+        // Safety: `inner` is structurally pinned, and `remaining` is not. Neither
+        // is moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (Pin::new_unchecked(&mut this.inner), &mut this.remaining)
+        }
+    }
+}
+
+impl<C: Coroutine> Coroutine for Take<C> {
+    type Yield = C::Yield;
+    type Resume = C::Resume;
+    type Return = Option<C::Return>;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (inner, remaining) = self.project();
+        if *remaining == 0 {
+            return GeneratorState::Complete(None);
+        }
+        match inner.resume_with(arg) {
+            GeneratorState::Yielded(y) => {
+                *remaining -= 1;
+                GeneratorState::Yielded(y)
+            }
+            GeneratorState::Complete(r) => {
+                *remaining = 0;
+                GeneratorState::Complete(Some(r))
+            }
+        }
+    }
+}
+
+impl_into_iterator!(Take<C>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    async fn produce(mut co: Co<i32, i32>) -> &'static str {
+        let mut n = 0;
+        for _ in 0..3 {
+            n = co.yield_(n).await;
+        }
+        "done"
+    }
+
+    #[test]
+    fn stops_after_n() {
+        let mut gen = Gen::new(produce).take(2);
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume_with(0), GeneratorState::Yielded(0));
+        assert_eq!(gen.as_mut().resume_with(5), GeneratorState::Yielded(5));
+        assert_eq!(gen.as_mut().resume_with(6), GeneratorState::Complete(None));
+    }
+
+    #[test]
+    fn passes_return_through_when_shorter() {
+        let mut gen = Gen::new(produce).take(5);
+        let mut gen = Pin::new(&mut gen);
+        for n in 0..3 {
+            assert_eq!(gen.as_mut().resume_with(n), GeneratorState::Yielded(n));
+        }
+        assert_eq!(
+            gen.as_mut().resume_with(0),
+            GeneratorState::Complete(Some("done"))
+        );
+        assert_eq!(gen.as_mut().resume_with(0), GeneratorState::Complete(None));
+    }
+
+    #[test]
+    fn into_iter() {
+        let gen = Gen::new(|mut co: Co<i32>| {
+            async move {
+                for i in 0.. {
+                    co.yield_(i).await;
+                }
+            }
+        });
+        let xs: Vec<_> = gen.take(3).into_iter().collect();
+        assert_eq!(xs, [0, 1, 2]);
+    }
+}
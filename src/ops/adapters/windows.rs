@@ -0,0 +1,126 @@
+use crate::ops::{adapters::IntoIter, Coroutine, GeneratorState};
+use alloc::{collections::VecDeque, vec::Vec};
+use core::pin::Pin;
+
+/// A coroutine which yields overlapping windows of the values yielded by
+/// another coroutine.
+///
+/// This is created by [`Coroutine::windows`].
+pub struct Windows<C: Coroutine> {
+    inner: C,
+    size: usize,
+    buffer: VecDeque<C::Yield>,
+}
+
+impl<C: Coroutine> Windows<C> {
+    pub(crate) fn new(inner: C, size: usize) -> Self {
+        assert!(size != 0, "`windows` was called with a size of 0");
+        Self {
+            inner,
+            size,
+            buffer: VecDeque::with_capacity(size),
+        }
+    }
+
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut C>, usize, &mut VecDeque<C::Yield>) {
+        // Safety: `inner` is structurally pinned, and the other fields are not.
+        // Nothing is moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (
+                Pin::new_unchecked(&mut this.inner),
+                this.size,
+                &mut this.buffer,
+            )
+        }
+    }
+}
+
+// `buffer` is not structurally pinned.
+impl<C: Coroutine + Unpin> Unpin for Windows<C> {}
+
+impl<C> Coroutine for Windows<C>
+where
+    C: Coroutine<Resume = ()>,
+    C::Yield: Clone,
+{
+    type Yield = Vec<C::Yield>;
+    type Resume = ();
+    type Return = C::Return;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        (): (),
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (mut inner, size, buffer) = self.project();
+        if buffer.len() == size {
+            buffer.pop_front();
+        }
+        while buffer.len() < size {
+            match inner.as_mut().resume_with(()) {
+                GeneratorState::Yielded(y) => buffer.push_back(y),
+                GeneratorState::Complete(r) => return GeneratorState::Complete(r),
+            }
+        }
+        GeneratorState::Yielded(buffer.iter().cloned().collect())
+    }
+}
+
+impl<C> IntoIterator for Windows<C>
+where
+    C: Coroutine<Resume = ()> + Unpin,
+    C::Yield: Clone,
+{
+    type Item = Vec<C::Yield>;
+    type IntoIter = IntoIter<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, Generator, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    async fn produce(mut co: Co<i32>) -> &'static str {
+        for i in 0..5 {
+            co.yield_(i).await;
+        }
+        "done"
+    }
+
+    #[test]
+    fn yields_windows() {
+        let xs: Vec<_> = Gen::new(produce).windows(3).into_iter().collect();
+        assert_eq!(xs, [vec![0, 1, 2], vec![1, 2, 3], vec![2, 3, 4]]);
+    }
+
+    #[test]
+    fn fewer_values_than_size() {
+        let mut gen = Gen::new(produce).windows(6);
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Complete("done"));
+    }
+
+    #[test]
+    fn no_partial_window_at_end() {
+        let mut gen = Gen::new(produce).windows(5);
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(
+            gen.as_mut().resume(),
+            GeneratorState::Yielded((0..5).collect()),
+        );
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Complete("done"));
+    }
+
+    #[test]
+    #[should_panic(expected = "`windows` was called with a size of 0")]
+    fn size_of_zero_panics() {
+        let _ = Gen::new(produce).windows(0);
+    }
+}
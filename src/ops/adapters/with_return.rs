@@ -0,0 +1,89 @@
+use crate::ops::{Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which yields the values from another coroutine, but returns a
+/// fixed value instead of its return value.
+///
+/// This is created by [`Coroutine::with_return`].
+pub struct WithReturn<C, T> {
+    inner: C,
+    value: Option<T>,
+}
+
+impl<C, T> WithReturn<C, T> {
+    pub(crate) fn new(inner: C, value: T) -> Self {
+        Self {
+            inner,
+            value: Some(value),
+        }
+    }
+
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut C>, &mut Option<T>) {
+        // Safety: `inner` is structurally pinned, and `value` is not. Neither is
+        // moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (Pin::new_unchecked(&mut this.inner), &mut this.value)
+        }
+    }
+}
+
+// `value` is not structurally pinned.
+impl<C: Unpin, T> Unpin for WithReturn<C, T> {}
+
+impl<C: Coroutine, T> Coroutine for WithReturn<C, T> {
+    type Yield = C::Yield;
+    type Resume = C::Resume;
+    type Return = T;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (inner, value) = self.project();
+        match inner.resume_with(arg) {
+            GeneratorState::Yielded(y) => GeneratorState::Yielded(y),
+            GeneratorState::Complete(_) => {
+                match value.take() {
+                    Some(value) => GeneratorState::Complete(value),
+                    None => panic!("`WithReturn` completed more than once"),
+                }
+            }
+        }
+    }
+}
+
+impl_into_iterator!(WithReturn<C, T>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::pin::Pin;
+
+    async fn produce(mut co: Co<i32, i32>) {
+        let n = co.yield_(1).await;
+        co.yield_(n).await;
+    }
+
+    #[test]
+    fn replaces_return() {
+        let mut gen = Gen::new(produce).with_return("done");
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume_with(0), GeneratorState::Yielded(1));
+        assert_eq!(gen.as_mut().resume_with(2), GeneratorState::Yielded(2));
+        assert_eq!(
+            gen.as_mut().resume_with(0),
+            GeneratorState::Complete("done"),
+        );
+    }
+
+    #[test]
+    fn into_iter() {
+        let gen = Gen::from_iter(vec![1, 2, 3]).with_return(String::new());
+        let xs: Vec<_> = gen.into_iter().collect();
+        assert_eq!(xs, [1, 2, 3]);
+    }
+}
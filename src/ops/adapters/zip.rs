@@ -0,0 +1,132 @@
+use crate::ops::{Coroutine, GeneratorState};
+use core::pin::Pin;
+
+/// A coroutine which drives two coroutines in lockstep.
+///
+/// This is created by [`Coroutine::zip`].
+pub struct Zip<C1, C2> {
+    first: C1,
+    second: C2,
+}
+
+impl<C1, C2> Zip<C1, C2> {
+    pub(crate) fn new(first: C1, second: C2) -> Self {
+        Self { first, second }
+    }
+
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut C1>, Pin<&mut C2>) {
+        // Safety: Both fields are structurally pinned. Neither is moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (
+                Pin::new_unchecked(&mut this.first),
+                Pin::new_unchecked(&mut this.second),
+            )
+        }
+    }
+}
+
+impl<C1, C2> Coroutine for Zip<C1, C2>
+where
+    C1: Coroutine,
+    C1::Resume: Clone,
+    C2: Coroutine<Resume = C1::Resume>,
+{
+    type Yield = (C1::Yield, C2::Yield);
+    type Resume = C1::Resume;
+    type Return = (Option<C1::Return>, Option<C2::Return>);
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        let (first, second) = self.project();
+        let a = match first.resume_with(arg.clone()) {
+            GeneratorState::Yielded(a) => a,
+            GeneratorState::Complete(r) => {
+                return GeneratorState::Complete((Some(r), None));
+            }
+        };
+        match second.resume_with(arg) {
+            GeneratorState::Yielded(b) => GeneratorState::Yielded((a, b)),
+            GeneratorState::Complete(r) => GeneratorState::Complete((None, Some(r))),
+        }
+    }
+}
+
+impl_into_iterator!(Zip<C1, C2>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, Generator, GeneratorState},
+        rc::{Co, Gen},
+    };
+    use std::{future::Future, pin::Pin};
+
+    fn count_to(n: i32) -> Gen<i32, (), impl Future<Output = i32>> {
+        Gen::new(move |mut co: Co<i32>| {
+            async move {
+                for i in 1..=n {
+                    co.yield_(i).await;
+                }
+                n
+            }
+        })
+    }
+
+    #[test]
+    fn equal_length() {
+        let mut gen = count_to(2).zip(count_to(2));
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Yielded((1, 1)));
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Yielded((2, 2)));
+        assert_eq!(
+            gen.as_mut().resume(),
+            GeneratorState::Complete((Some(2), None))
+        );
+    }
+
+    #[test]
+    fn left_shorter() {
+        let mut gen = count_to(1).zip(count_to(3));
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Yielded((1, 1)));
+        assert_eq!(
+            gen.as_mut().resume(),
+            GeneratorState::Complete((Some(1), None))
+        );
+    }
+
+    #[test]
+    fn right_shorter() {
+        let mut gen = count_to(3).zip(count_to(1));
+        let mut gen = Pin::new(&mut gen);
+        assert_eq!(gen.as_mut().resume(), GeneratorState::Yielded((1, 1)));
+        assert_eq!(
+            gen.as_mut().resume(),
+            GeneratorState::Complete((None, Some(1)))
+        );
+    }
+
+    #[test]
+    fn forwards_resume_arguments_to_both() {
+        let echo = || {
+            Gen::new(|mut co: Co<i32, i32>| {
+                async move {
+                    let mut n = 0;
+                    loop {
+                        n = co.yield_(n).await;
+                    }
+                }
+            })
+        };
+        let xs: Vec<_> = echo()
+            .zip(echo())
+            .map_resume(|()| 7)
+            .into_iter()
+            .take(2)
+            .collect();
+        assert_eq!(xs, [(0, 0), (7, 7)]);
+    }
+}
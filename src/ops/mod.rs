@@ -0,0 +1,952 @@
+use crate::ops::adapters::{
+    Chain,
+    Chunks,
+    Cloned,
+    Copied,
+    Dedup,
+    DedupBy,
+    Enumerate,
+    Event,
+    Filter,
+    FlatMap,
+    Flatten,
+    Inspect,
+    Intersperse,
+    MapResume,
+    MapYield,
+    Observe,
+    Peekable,
+    Race,
+    Retaining,
+    Scan,
+    Skip,
+    StepBy,
+    Take,
+    Windows,
+    WithReturn,
+    Zip,
+};
+use alloc::{boxed::Box, vec::Vec};
+use core::{fmt, pin::Pin};
+
+pub mod adapters;
+
+/// A trait implemented for coroutines.
+///
+/// A `Coroutine` is a generalization of a `Generator`. A `Generator` constrains
+/// the resume argument type to `()`, but in a `Coroutine` it can be anything.
+pub trait Coroutine {
+    /// The type of value this generator yields.
+    type Yield;
+
+    /// The type of value this generator accepts as a resume argument.
+    type Resume;
+
+    /// The type of value this generator returns upon completion.
+    type Return;
+
+    /// Resumes the execution of this generator.
+    ///
+    /// The argument will be passed into the coroutine as a resume argument.
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return>;
+
+    /// Boxes this coroutine behind a trait object, erasing its concrete type.
+    ///
+    /// This lets you store coroutines of different types together, e.g. in a
+    /// `Vec`.
+    fn into_boxed_coroutine<'a>(
+        self,
+    ) -> BoxedCoroutine<'a, Self::Yield, Self::Resume, Self::Return>
+    where
+        Self: Sized + 'a,
+    {
+        Box::pin(self)
+    }
+
+    /// Creates a coroutine which transforms each yielded value with `f`.
+    ///
+    /// Resume arguments and the return value are passed through unchanged.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn map_yield<Y, F>(self, f: F) -> MapYield<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Yield) -> Y,
+    {
+        MapYield::new(self, f)
+    }
+
+    /// Creates a coroutine which transforms each resume argument with `f`
+    /// before passing it along.
+    ///
+    /// Yielded values and the return value are passed through unchanged.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn map_resume<R, F>(self, f: F) -> MapResume<Self, F, R>
+    where
+        Self: Sized,
+        F: FnMut(R) -> Self::Resume,
+    {
+        MapResume::new(self, f)
+    }
+
+    /// Creates a coroutine which runs this coroutine to completion, then runs
+    /// `next`.
+    ///
+    /// Resume arguments go to whichever coroutine is currently running. The
+    /// return value of this coroutine is dropped, and the return value of
+    /// `next` is returned. Since the resume argument which completed this
+    /// coroutine has already been used up, `next` is first resumed with
+    /// `Default::default()`.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn chain<C>(self, next: C) -> Chain<Self, C>
+    where
+        Self: Sized,
+        Self::Resume: Default,
+        C: Coroutine<Yield = Self::Yield, Resume = Self::Resume>,
+    {
+        Chain::new(self, next)
+    }
+
+    /// Creates a coroutine which drives this coroutine and `other` in
+    /// lockstep, yielding pairs of their values.
+    ///
+    /// Each resume argument is cloned and passed to both coroutines. This
+    /// coroutine completes as soon as either of them does, returning the
+    /// return value of whichever one completed. If this coroutine yields a
+    /// value but `other` completes, that value is dropped.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn zip<C>(self, other: C) -> Zip<Self, C>
+    where
+        Self: Sized,
+        Self::Resume: Clone,
+        C: Coroutine<Resume = Self::Resume>,
+    {
+        Zip::new(self, other)
+    }
+
+    /// Creates a coroutine which yields at most `n` values.
+    ///
+    /// After `n` values have been yielded, the next resumption completes with
+    /// `None`, without resuming this coroutine again. If this coroutine
+    /// completes first, its return value is returned in `Some`.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take::new(self, n)
+    }
+
+    /// Creates a coroutine which discards the first `n` yielded values.
+    ///
+    /// The skipping happens during the first resumption. This coroutine is
+    /// resumed with a clone of that first resume argument for each skipped
+    /// value, and then once more with the argument itself.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn skip(self, n: usize) -> Skip<Self>
+    where
+        Self: Sized,
+        Self::Resume: Clone,
+    {
+        Skip::new(self, n)
+    }
+
+    /// Creates a coroutine which transforms each yielded value with `f`, while
+    /// threading a state through, starting from `init`.
+    ///
+    /// `f` receives the state and the yielded value. If it returns `None`, the
+    /// coroutine completes with `None` right away. If this coroutine completes
+    /// first, its return value is returned in `Some`. Resume arguments are
+    /// passed through unchanged.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn scan<St, Out, F>(self, init: St, f: F) -> Scan<Self, St, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut St, Self::Yield) -> Option<Out>,
+    {
+        Scan::new(self, init, f)
+    }
+
+    /// Creates a coroutine which only yields the values for which `pred`
+    /// returns `true`.
+    ///
+    /// Each resumption keeps resuming this coroutine until a value passes or
+    /// it completes. Since that can take several resumptions, this coroutine
+    /// is resumed with a clone of the resume argument each time.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn filter<P>(self, pred: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+        Self::Resume: Clone,
+        P: FnMut(&Self::Yield) -> bool,
+    {
+        Filter::new(self, pred)
+    }
+
+    /// Creates a coroutine which yields the first value, and then every
+    /// `step`-th value after it.
+    ///
+    /// The values in between are skipped by resuming this coroutine again, so
+    /// it is resumed with a clone of the resume argument each time. If this
+    /// coroutine completes while values are being skipped, its return value is
+    /// passed through.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is 0.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn step_by(self, step: usize) -> StepBy<Self>
+    where
+        Self: Sized,
+        Self::Resume: Clone,
+    {
+        StepBy::new(self, step)
+    }
+
+    /// Creates a coroutine which runs each coroutine this one yields, and yields
+    /// their values in turn.
+    ///
+    /// Each resumption resumes the current inner coroutine once. Once it
+    /// completes, its return value is dropped, and this coroutine is resumed to
+    /// get the next one. Since an empty inner coroutine means resuming this
+    /// coroutine again, it is resumed with a clone of the resume argument each
+    /// time.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn flatten(self) -> Flatten<Self>
+    where
+        Self: Sized,
+        Self::Resume: Clone,
+        Self::Yield: Coroutine<Resume = ()>,
+    {
+        Flatten::new(self)
+    }
+
+    /// Creates a coroutine which yields each value along with its index,
+    /// starting from 0.
+    ///
+    /// The index only counts yielded values. Resume arguments and the return
+    /// value are passed through unchanged.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn enumerate(self) -> Enumerate<Self>
+    where
+        Self: Sized,
+    {
+        Enumerate::new(self)
+    }
+
+    /// Creates a coroutine which calls `f` with a reference to each yielded
+    /// value, before passing it on.
+    ///
+    /// `f` is not called when this coroutine completes. Resume arguments and
+    /// the return value are passed through unchanged.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn inspect<G>(self, f: G) -> Inspect<Self, G>
+    where
+        Self: Sized,
+        G: FnMut(&Self::Yield),
+    {
+        Inspect::new(self, f)
+    }
+
+    /// Creates a coroutine which advances this coroutine and `other`, preferring
+    /// this one.
+    ///
+    /// Each resumption resumes this coroutine, and yields its value as
+    /// `Either::Left`. Once this coroutine completes, `other` is resumed
+    /// instead, and its values are yielded as `Either::Right`. The policy is
+    /// left-biased: since resuming a coroutine always produces a value, `other`
+    /// only runs after this one is done. Once both have completed, their return
+    /// values are returned together.
+    ///
+    /// As with [`chain`](#method.chain), `other` is first resumed with
+    /// `Default::default()`.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn race<C>(self, other: C) -> Race<Self, C>
+    where
+        Self: Sized,
+        Self::Resume: Default,
+        C: Coroutine<Resume = Self::Resume>,
+    {
+        Race::new(self, other)
+    }
+
+    /// Creates a coroutine which yields the same values as this one, but
+    /// returns `value` instead of this coroutine's return value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this coroutine completes more than once.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn with_return<T>(self, value: T) -> WithReturn<Self, T>
+    where
+        Self: Sized,
+    {
+        WithReturn::new(self, value)
+    }
+
+    /// Creates a coroutine which clones each value that this coroutine yields a
+    /// reference to.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn cloned<'a, T>(self) -> Cloned<Self>
+    where
+        Self: Sized + Coroutine<Yield = &'a T>,
+        T: 'a + Clone,
+    {
+        Cloned::new(self)
+    }
+
+    /// Creates a coroutine which copies each value that this coroutine yields a
+    /// reference to.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn copied<'a, T>(self) -> Copied<Self>
+    where
+        Self: Sized + Coroutine<Yield = &'a T>,
+        T: 'a + Copy,
+    {
+        Copied::new(self)
+    }
+
+    /// Creates a coroutine which calls a function on each [`Event`]: every
+    /// resume argument before it is passed on, and every yielded or returned
+    /// value after it comes out.
+    ///
+    /// This is like [`inspect`](Coroutine::inspect), but sees everything which
+    /// passes through the coroutine, which helps when debugging pipelines.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn observe<G>(self, f: G) -> Observe<Self, G>
+    where
+        Self: Sized,
+        G: FnMut(&Event<'_, Self::Yield, Self::Resume, Self::Return>),
+    {
+        Observe::new(self, f)
+    }
+
+    /// Creates a coroutine which collects the yielded values into chunks of
+    /// `size` values each.
+    ///
+    /// Each resumption resumes this coroutine up to `size` times. If it
+    /// completes partway through a chunk, the shorter chunk is yielded first,
+    /// and the return value is returned on the next resumption.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn chunks(self, size: usize) -> Chunks<Self>
+    where
+        Self: Sized + Coroutine<Resume = ()>,
+    {
+        Chunks::new(self, size)
+    }
+
+    /// Creates a coroutine which yields overlapping windows of the last `size`
+    /// yielded values.
+    ///
+    /// Nothing is yielded until `size` values have been seen, and once this
+    /// coroutine completes, no shorter window is yielded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn windows(self, size: usize) -> Windows<Self>
+    where
+        Self: Sized + Coroutine<Resume = ()>,
+        Self::Yield: Clone,
+    {
+        Windows::new(self, size)
+    }
+
+    /// Creates a coroutine which skips values which are equal to the value
+    /// yielded just before them.
+    ///
+    /// Each value is held back until the next different value is yielded, or
+    /// until this coroutine completes. The return value is kept.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn dedup(self) -> Dedup<Self>
+    where
+        Self: Sized + Coroutine<Resume = ()>,
+        Self::Yield: PartialEq,
+    {
+        Dedup::new(self)
+    }
+
+    /// Creates a coroutine which skips values for which `eq` returns `true`
+    /// when compared with the value yielded just before them.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn dedup_by<F>(self, eq: F) -> DedupBy<Self, F>
+    where
+        Self: Sized + Coroutine<Resume = ()>,
+        F: FnMut(&Self::Yield, &Self::Yield) -> bool,
+    {
+        DedupBy::new(self, eq)
+    }
+
+    /// Creates a coroutine which yields a clone of `sep` between each pair of
+    /// yielded values.
+    ///
+    /// No separator is yielded before the first value or after the last one.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn intersperse(self, sep: Self::Yield) -> Intersperse<Self>
+    where
+        Self: Sized + Coroutine<Resume = ()>,
+        Self::Yield: Clone,
+    {
+        Intersperse::new(self, sep)
+    }
+
+    /// Creates a coroutine which maps each yielded value to an iterator, and
+    /// yields the items of each iterator in turn.
+    ///
+    /// Each iterator is drained before this coroutine is resumed again.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn flat_map<I, G>(self, f: G) -> FlatMap<Self, G, I>
+    where
+        Self: Sized + Coroutine<Resume = ()>,
+        G: FnMut(Self::Yield) -> I,
+        I: IntoIterator,
+    {
+        FlatMap::new(self, f)
+    }
+
+    /// Creates a coroutine which keeps the return value once this coroutine
+    /// completes, so it can be borrowed afterwards with
+    /// [`CompletedCoroutine::return_ref`].
+    ///
+    /// The new coroutine completes with `()`, and resuming it after that keeps
+    /// returning `Complete(())` without resuming this coroutine again.
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn retaining(self) -> Retaining<Self>
+    where
+        Self: Sized,
+    {
+        Retaining::new(self)
+    }
+
+    /// Creates a coroutine which can look at the next yielded value without
+    /// consuming it, using [`Peekable::peek`].
+    ///
+    /// [_See the module-level docs for examples._](crate::adapters)
+    fn peekable(self) -> Peekable<Self>
+    where
+        Self: Sized + Coroutine<Resume = ()>,
+    {
+        Peekable::new(self)
+    }
+}
+
+/// This is a type alias for coroutines whose type has been erased.
+///
+/// These are created by [`Coroutine::into_boxed_coroutine`].
+pub type BoxedCoroutine<'a, Y, R = (), C = ()> =
+    Pin<Box<dyn Coroutine<Yield = Y, Resume = R, Return = C> + 'a>>;
+
+/// A trait implemented for generator types.
+///
+/// This is modeled after the stdlib's nightly-only [`std::ops::Generator`].
+pub trait Generator {
+    /// The type of value this generator yields.
+    type Yield;
+
+    /// The type of value this generator returns upon completion.
+    type Return;
+
+    /// Resumes the execution of this generator.
+    fn resume(self: Pin<&mut Self>) -> GeneratorState<Self::Yield, Self::Return>;
+}
+
+impl<C: Coroutine<Resume = ()>> Generator for C {
+    type Yield = <Self as Coroutine>::Yield;
+    type Return = <Self as Coroutine>::Return;
+
+    #[must_use]
+    fn resume(self: Pin<&mut Self>) -> GeneratorState<Self::Yield, Self::Return> {
+        self.resume_with(())
+    }
+}
+
+/// An extension trait for resuming `Unpin` coroutines without pinning them
+/// first.
+///
+/// This is implemented for every coroutine which is `Unpin`, which includes all
+/// `rc` and `sync` generators.
+pub trait ResumeExt: Coroutine + Unpin {
+    /// Resumes the execution of this coroutine through a plain `&mut`.
+    ///
+    /// This is the same as calling `Pin::new(self).resume_with(arg)`.
+    fn resume_mut(
+        &mut self,
+        arg: Self::Resume,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        Pin::new(self).resume_with(arg)
+    }
+}
+
+impl<C: Coroutine + Unpin + ?Sized> ResumeExt for C {}
+
+/// A trait for coroutines which keep their return value after they complete.
+///
+/// This is implemented by [`Retaining`], which is created by
+/// [`Coroutine::retaining`].
+pub trait CompletedCoroutine: Coroutine {
+    /// The type of the return value which is kept.
+    type Retained;
+
+    /// Borrows the return value, or returns `None` if the coroutine has not
+    /// completed yet.
+    fn return_ref(&self) -> Option<&Self::Retained>;
+}
+
+/// A trait for turning a producer function into a coroutine, without naming the
+/// generator type.
+///
+/// This is implemented for every `FnOnce(Co<Y, R>) -> impl Future`, for both
+/// [`rc::Co`](crate::rc::Co) and [`sync::Co`](crate::sync::Co). The type
+/// parameter is the type of `Co`, so annotating the closure's argument is
+/// enough for `Y` and `R` to be inferred, and picks which kind of generator is
+/// created.
+///
+/// ```rust
+/// use genawaiter::{rc::Co, Coroutine, IntoCoroutine};
+///
+/// let gen = (|mut co: Co<i32>| {
+///     async move {
+///         co.yield_(1).await;
+///         co.yield_(2).await;
+///     }
+/// })
+/// .into_coroutine()
+/// .map_yield(|n| n * 10);
+/// let xs: Vec<_> = gen.into_iter().collect();
+/// assert_eq!(xs, [10, 20]);
+/// ```
+pub trait IntoCoroutine<Co> {
+    /// The type of coroutine which is created.
+    type Coroutine: Coroutine;
+
+    /// Creates a coroutine from this producer.
+    fn into_coroutine(self) -> Self::Coroutine;
+}
+
+/// Resumes a coroutine once with each value from `inputs`, and collects the
+/// values it yields.
+///
+/// If the coroutine completes before `inputs` runs out, the rest of `inputs` is
+/// not used, and the return value is returned in `Some`. Otherwise `None` is
+/// returned. As usual, the first resume argument is not observed by the
+/// coroutine.
+///
+/// This is re-exported as `rc::drive_with` and `sync::drive_with`.
+pub fn drive_with<C, I>(
+    mut coroutine: C,
+    inputs: I,
+) -> (Vec<C::Yield>, Option<C::Return>)
+where
+    C: Coroutine + Unpin,
+    I: IntoIterator<Item = C::Resume>,
+{
+    let mut values = Vec::new();
+    for arg in inputs {
+        match coroutine.resume_mut(arg) {
+            GeneratorState::Yielded(y) => values.push(y),
+            GeneratorState::Complete(r) => return (values, Some(r)),
+        }
+    }
+    (values, None)
+}
+
+/// The result of a generator resumption.
+///
+/// This is modeled after the stdlib's nightly-only
+/// [`std::ops::GeneratorState`].
+#[derive(PartialEq, Eq, Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub enum GeneratorState<Y, R> {
+    /// The generator suspended with a value.
+    Yielded(Y),
+
+    /// The generator completed with a return value.
+    Complete(R),
+}
+
+impl<Y, R> GeneratorState<Y, R> {
+    /// Returns the yielded value, or `None` if the generator completed.
+    pub fn yielded(self) -> Option<Y> {
+        match self {
+            Self::Yielded(y) => Some(y),
+            Self::Complete(_) => None,
+        }
+    }
+
+    /// Returns the return value, or `None` if the generator yielded.
+    pub fn complete(self) -> Option<R> {
+        match self {
+            Self::Yielded(_) => None,
+            Self::Complete(r) => Some(r),
+        }
+    }
+
+    /// Returns `true` if the generator yielded a value.
+    ///
+    /// ```rust
+    /// # use genawaiter::GeneratorState;
+    /// let state: GeneratorState<i32, &str> = GeneratorState::Yielded(10);
+    /// assert!(state.is_yielded());
+    /// assert!(!state.is_complete());
+    /// ```
+    #[must_use]
+    pub fn is_yielded(&self) -> bool {
+        match self {
+            Self::Yielded(_) => true,
+            Self::Complete(_) => false,
+        }
+    }
+
+    /// Returns `true` if the generator completed.
+    ///
+    /// ```rust
+    /// # use genawaiter::GeneratorState;
+    /// let state: GeneratorState<i32, &str> = GeneratorState::Complete("done");
+    /// assert!(state.is_complete());
+    /// assert!(!state.is_yielded());
+    /// ```
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        match self {
+            Self::Yielded(_) => false,
+            Self::Complete(_) => true,
+        }
+    }
+
+    /// Returns the yielded value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generator completed, with a message including the return
+    /// value.
+    ///
+    /// ```rust,should_panic
+    /// # use genawaiter::GeneratorState;
+    /// let state: GeneratorState<i32, &str> = GeneratorState::Complete("done");
+    /// // panics with `called `GeneratorState::unwrap_yielded()` on a `Complete`
+    /// // value: "done"`
+    /// state.unwrap_yielded();
+    /// ```
+    pub fn unwrap_yielded(self) -> Y
+    where
+        R: fmt::Debug,
+    {
+        match self {
+            Self::Yielded(y) => y,
+            Self::Complete(r) => {
+                panic!(
+                    "called `GeneratorState::unwrap_yielded()` on a `Complete` value: \
+                     {:?}",
+                    r
+                )
+            }
+        }
+    }
+
+    /// Returns the yielded value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generator completed, with a message including `msg` and
+    /// the return value.
+    ///
+    /// ```rust,should_panic
+    /// # use genawaiter::GeneratorState;
+    /// let state: GeneratorState<i32, &str> = GeneratorState::Complete("done");
+    /// // panics with `expected a value: "done"`
+    /// state.expect_yielded("expected a value");
+    /// ```
+    pub fn expect_yielded(self, msg: &str) -> Y
+    where
+        R: fmt::Debug,
+    {
+        match self {
+            Self::Yielded(y) => y,
+            Self::Complete(r) => panic!("{}: {:?}", msg, r),
+        }
+    }
+
+    /// Returns the return value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generator yielded, with a message including the yielded
+    /// value.
+    ///
+    /// ```rust,should_panic
+    /// # use genawaiter::GeneratorState;
+    /// let state: GeneratorState<i32, &str> = GeneratorState::Yielded(10);
+    /// // panics with `called `GeneratorState::unwrap_complete()` on a `Yielded`
+    /// // value: 10`
+    /// state.unwrap_complete();
+    /// ```
+    pub fn unwrap_complete(self) -> R
+    where
+        Y: fmt::Debug,
+    {
+        match self {
+            Self::Yielded(y) => {
+                panic!(
+                    "called `GeneratorState::unwrap_complete()` on a `Yielded` value: \
+                     {:?}",
+                    y
+                )
+            }
+            Self::Complete(r) => r,
+        }
+    }
+
+    /// Converts from `&GeneratorState<Y, R>` to `GeneratorState<&Y, &R>`.
+    pub fn as_ref(&self) -> GeneratorState<&Y, &R> {
+        match self {
+            Self::Yielded(y) => GeneratorState::Yielded(y),
+            Self::Complete(r) => GeneratorState::Complete(r),
+        }
+    }
+
+    /// Transforms the yielded value with `f`, leaving a return value untouched.
+    pub fn map_yielded<Y2>(self, f: impl FnOnce(Y) -> Y2) -> GeneratorState<Y2, R> {
+        match self {
+            Self::Yielded(y) => GeneratorState::Yielded(f(y)),
+            Self::Complete(r) => GeneratorState::Complete(r),
+        }
+    }
+
+    /// Transforms the return value with `f`, leaving a yielded value untouched.
+    pub fn map_complete<R2>(self, f: impl FnOnce(R) -> R2) -> GeneratorState<Y, R2> {
+        match self {
+            Self::Yielded(y) => GeneratorState::Yielded(y),
+            Self::Complete(r) => GeneratorState::Complete(f(r)),
+        }
+    }
+}
+
+/// A yielded value continues the flow, and a return value breaks it.
+///
+/// This requires the `control_flow` feature.
+#[cfg(feature = "control_flow")]
+impl<Y, R> From<GeneratorState<Y, R>> for core::ops::ControlFlow<R, Y> {
+    fn from(state: GeneratorState<Y, R>) -> Self {
+        match state {
+            GeneratorState::Yielded(y) => Self::Continue(y),
+            GeneratorState::Complete(r) => Self::Break(r),
+        }
+    }
+}
+
+/// `Continue` becomes a yielded value, and `Break` becomes a return value.
+///
+/// This requires the `control_flow` feature.
+#[cfg(feature = "control_flow")]
+impl<Y, R> From<core::ops::ControlFlow<R, Y>> for GeneratorState<Y, R> {
+    fn from(flow: core::ops::ControlFlow<R, Y>) -> Self {
+        match flow {
+            core::ops::ControlFlow::Continue(y) => Self::Yielded(y),
+            core::ops::ControlFlow::Break(r) => Self::Complete(r),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        rc,
+        sync,
+        BoxedCoroutine,
+        Coroutine,
+        GeneratorState,
+        IntoCoroutine,
+        ResumeExt,
+    };
+    use std::{cell::RefCell, marker::PhantomPinned};
+
+    #[test]
+    fn boxed_coroutines_can_be_stored_together() {
+        let log = &RefCell::new(Vec::new());
+        let rc_gen = rc::Gen::new(|mut co| {
+            async move {
+                log.borrow_mut().push("rc 1");
+                co.yield_(()).await;
+                log.borrow_mut().push("rc 2");
+            }
+        });
+        let sync_gen = sync::Gen::new(|mut co| {
+            async move {
+                log.borrow_mut().push("sync 1");
+                co.yield_(()).await;
+                log.borrow_mut().push("sync 2");
+            }
+        });
+
+        let mut gens: Vec<BoxedCoroutine<'_, ()>> = vec![
+            rc_gen.into_boxed_coroutine(),
+            sync_gen.into_boxed_coroutine(),
+        ];
+        for gen in &mut gens {
+            assert_eq!(gen.as_mut().resume_with(()), GeneratorState::Yielded(()));
+        }
+        for gen in &mut gens {
+            assert_eq!(gen.as_mut().resume_with(()), GeneratorState::Complete(()));
+        }
+        assert_eq!(*log.borrow(), ["rc 1", "sync 1", "rc 2", "sync 2"]);
+    }
+
+    #[test]
+    fn gens_are_unpin_even_if_values_are_not() {
+        fn assert_unpin<T: Unpin>() {}
+
+        assert_unpin::<rc::GenBoxed<PhantomPinned, PhantomPinned, PhantomPinned>>();
+        assert_unpin::<sync::GenBoxed<PhantomPinned, PhantomPinned, PhantomPinned>>();
+    }
+
+    #[test]
+    fn resume_mut() {
+        let mut gen = rc::Gen::new(|mut co| {
+            async move {
+                let n = co.yield_(1).await;
+                n * 10
+            }
+        })
+        .map_yield(|n| n + 1);
+        assert_eq!(gen.resume_mut(0), GeneratorState::Yielded(2));
+        assert_eq!(gen.resume_mut(5), GeneratorState::Complete(50));
+    }
+
+    #[test]
+    fn into_coroutine() {
+        let rc_gen = (|mut co: rc::Co<i32>| {
+            async move {
+                co.yield_(1).await;
+                co.yield_(2).await;
+                "done"
+            }
+        })
+        .into_coroutine()
+        .map_yield(|n| n * 10);
+        let xs: Vec<_> = rc_gen.into_iter().collect();
+        assert_eq!(xs, [10, 20]);
+
+        let mut sync_gen = (|mut co: sync::Co<i32, i32>| {
+            async move {
+                let n = co.yield_(1).await;
+                n + 1
+            }
+        })
+        .into_coroutine();
+        assert_eq!(sync_gen.resume_with(0), GeneratorState::Yielded(1));
+        assert_eq!(sync_gen.resume_with(5), GeneratorState::Complete(6));
+    }
+
+    #[test]
+    fn state_accessors() {
+        let yielded: GeneratorState<i32, &str> = GeneratorState::Yielded(1);
+        let complete: GeneratorState<i32, &str> = GeneratorState::Complete("done");
+
+        assert_eq!(yielded.as_ref(), GeneratorState::Yielded(&1));
+        assert_eq!(complete.as_ref(), GeneratorState::Complete(&"done"));
+        assert_eq!(yielded.as_ref().yielded(), Some(&1));
+        assert_eq!(complete.as_ref().yielded(), None);
+        assert_eq!(yielded.as_ref().complete(), None);
+        assert_eq!(complete.as_ref().complete(), Some(&"done"));
+        assert!(yielded.is_yielded());
+        assert!(!yielded.is_complete());
+        assert!(complete.is_complete());
+        assert!(!complete.is_yielded());
+    }
+
+    #[test]
+    fn state_unwraps() {
+        let yielded = || GeneratorState::<i32, &str>::Yielded(1);
+        let complete = || GeneratorState::<i32, &str>::Complete("done");
+
+        assert_eq!(yielded().unwrap_yielded(), 1);
+        assert_eq!(yielded().expect_yielded("value"), 1);
+        assert_eq!(complete().unwrap_complete(), "done");
+    }
+
+    #[test]
+    #[should_panic(expected = "called `GeneratorState::unwrap_yielded()` on a \
+                               `Complete` value: \"done\"")]
+    fn unwrap_yielded_on_complete() {
+        GeneratorState::<i32, &str>::Complete("done").unwrap_yielded();
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a value: \"done\"")]
+    fn expect_yielded_on_complete() {
+        GeneratorState::<i32, &str>::Complete("done")
+            .expect_yielded("expected a value");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "called `GeneratorState::unwrap_complete()` on a `Yielded` value: 1"
+    )]
+    fn unwrap_complete_on_yielded() {
+        GeneratorState::<i32, &str>::Yielded(1).unwrap_complete();
+    }
+
+    #[test]
+    fn state_maps() {
+        let yielded = || GeneratorState::<i32, &str>::Yielded(1);
+        let complete = || GeneratorState::<i32, &str>::Complete("done");
+
+        assert_eq!(yielded().map_yielded(|n| n + 1), GeneratorState::Yielded(2));
+        assert_eq!(
+            complete().map_yielded(|n| n + 1),
+            GeneratorState::Complete("done"),
+        );
+        assert_eq!(yielded().map_complete(str::len), GeneratorState::Yielded(1));
+        assert_eq!(
+            complete().map_complete(str::len),
+            GeneratorState::Complete(4),
+        );
+    }
+
+    #[cfg(feature = "control_flow")]
+    #[test]
+    fn control_flow_round_trip() {
+        use std::ops::ControlFlow;
+
+        let flow: ControlFlow<&str, i32> = GeneratorState::Yielded(1).into();
+        assert_eq!(flow, ControlFlow::Continue(1));
+        let flow: ControlFlow<&str, i32> = GeneratorState::Complete("done").into();
+        assert_eq!(flow, ControlFlow::Break("done"));
+
+        let state: GeneratorState<i32, &str> = ControlFlow::Continue(1).into();
+        assert_eq!(state, GeneratorState::Yielded(1));
+        let state: GeneratorState<i32, &str> = ControlFlow::Break("done").into();
+        assert_eq!(state, GeneratorState::Complete("done"));
+    }
+}
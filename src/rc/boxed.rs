@@ -0,0 +1,68 @@
+use crate::rc::{Co, Gen};
+use alloc::boxed::Box;
+use core::{future::Future, pin::Pin};
+
+/// This is a type alias for generators whose future has been boxed and erased.
+///
+/// Since the type of the future is not part of the generator's type, this lets
+/// you store generators created from unrelated producers together, e.g. in a
+/// `Vec`.
+#[allow(clippy::module_name_repetitions)]
+pub type GenBoxed<Y, R = (), C = ()> = Gen<Y, R, Pin<Box<dyn Future<Output = C>>>>;
+
+impl<Y, R, C> GenBoxed<Y, R, C> {
+    /// Creates a new generator with a boxed future.
+    ///
+    /// This works exactly the same as [`Gen::new`](struct.Gen.html#method.new)
+    /// with an immediately boxed future.
+    ///
+    /// This method exists solely to help the compiler with type inference.
+    /// These two lines are equivalent, except that the compiler cannot infer
+    /// the correct type on the second line:
+    ///
+    /// ```compile_fail
+    /// # use genawaiter::rc::{Co, Gen, GenBoxed};
+    /// # use std::{future::Future, pin::Pin};
+    /// #
+    /// # async fn producer(co: Co<i32>) {
+    /// #     for n in (1..).step_by(2).take_while(|&n| n < 10) { co.yield_(n).await; }
+    /// # }
+    /// #
+    /// let _: GenBoxed<i32> = Gen::new_boxed(|co| producer(co));
+    /// let _: GenBoxed<i32> = Gen::new(|co| Box::pin(producer(co)));
+    /// ```
+    pub fn new_boxed<F>(producer: impl FnOnce(Co<Y, R>) -> F) -> Self
+    where
+        F: Future<Output = C> + 'static,
+    {
+        Self::new(|co| Box::pin(producer(co)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rc::{Co, Gen, GenBoxed};
+
+    async fn odd_numbers_less_than_ten(mut co: Co<i32>) {
+        for n in (1..).step_by(2).take_while(|&n| n < 10) {
+            co.yield_(n).await;
+        }
+    }
+
+    async fn even_numbers_less_than_ten(mut co: Co<i32>) {
+        for n in (0..).step_by(2).take_while(|&n| n < 10) {
+            co.yield_(n).await;
+        }
+    }
+
+    #[test]
+    fn can_be_stored_together() {
+        let gens: Vec<GenBoxed<i32>> = vec![
+            Gen::new_boxed(odd_numbers_less_than_ten),
+            Gen::new_boxed(even_numbers_less_than_ten),
+        ];
+        let xs: Vec<Vec<_>> =
+            gens.into_iter().map(|g| g.into_iter().collect()).collect();
+        assert_eq!(xs, [[1, 3, 5, 7, 9], [0, 2, 4, 6, 8]]);
+    }
+}
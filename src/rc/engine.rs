@@ -1,6 +1,12 @@
 use crate::{core, core::Next};
-use std::{cell::Cell, rc::Rc};
+use ::core::cell::Cell;
+use alloc::rc::Rc;
 
+/// The slot through which values pass between the generator and its caller.
+///
+/// Since `rc` generators are single-threaded and never reentrant, this is a plain
+/// `Cell`. Every transition is a single `Cell::replace`, so neither a `RefCell`
+/// borrow flag nor any allocation is involved on the hot path.
 pub struct Airlock<Y, R>(Rc<Cell<Next<Y, R>>>);
 
 impl<Y, R> Default for Airlock<Y, R> {
@@ -15,6 +21,8 @@ impl<Y, R> Clone for Airlock<Y, R> {
     }
 }
 
+impl<Y, R> core::sealed::Sealed for Airlock<Y, R> {}
+
 impl<Y, R> core::Airlock for Airlock<Y, R> {
     type Yield = Y;
     type Resume = R;
@@ -40,3 +48,14 @@ impl<Y, R> core::Airlock for Airlock<Y, R> {
 ///
 /// [_See the module-level docs for examples._](.)
 pub type Co<Y, R = ()> = core::Co<Airlock<Y, R>>;
+
+impl<Y, R> Co<Y, R> {
+    /// Returns the airlock which this generator uses to pass values to and from
+    /// its caller.
+    ///
+    /// This is meant for debugging and for custom drivers. See
+    /// [`Airlock`](crate::Airlock) for the steps the airlock goes through.
+    pub fn airlock(&self) -> &Airlock<Y, R> {
+        &self.airlock
+    }
+}
@@ -1,16 +1,27 @@
 use crate::{
     core::{advance, async_advance, Airlock as _, Next},
-    ops::{Coroutine, GeneratorState},
+    ops::{Coroutine, GeneratorState, IntoCoroutine},
     rc::{engine::Airlock, Co},
 };
-use std::{future::Future, pin::Pin};
+use alloc::{boxed::Box, vec::Vec};
+use core::{fmt, future::Future, pin::Pin};
+#[cfg(feature = "std")]
+use std::{collections::HashMap, hash::Hash};
 
 /// This is a generator which stores its state on the heap.
 ///
+/// Resuming takes `&mut self`, so a generator can never be resumed again from
+/// inside its own body. Code which tries, by sharing the generator through an
+/// `Rc<RefCell<Gen<..>>>`, panics when the `RefCell` is borrowed a second time.
+/// The airlock itself holds no borrow which could be left dangling.
+///
 /// [_See the module-level docs for examples._](.)
 pub struct Gen<Y, R, F: Future> {
     airlock: Airlock<Y, R>,
     future: Pin<Box<F>>,
+    done: bool,
+    poisoned: bool,
+    return_value: Option<F::Output>,
 }
 
 impl<Y, R, F: Future> Gen<Y, R, F> {
@@ -29,7 +40,13 @@ impl<Y, R, F: Future> Gen<Y, R, F> {
     pub fn new(producer: impl FnOnce(Co<Y, R>) -> F) -> Self {
         let airlock = Airlock::default();
         let future = { Box::pin(producer(Co::new(airlock.clone()))) };
-        Self { airlock, future }
+        Self {
+            airlock,
+            future,
+            done: false,
+            poisoned: false,
+            return_value: None,
+        }
     }
 
     /// Resumes execution of the generator.
@@ -41,10 +58,131 @@ impl<Y, R, F: Future> Gen<Y, R, F> {
     /// If the generator yields a value, `Yielded` is returned. Otherwise,
     /// `Completed` is returned.
     ///
+    /// # Panics
+    ///
+    /// Panics if [`try_resume`](#method.try_resume) has already returned an
+    /// error.
+    ///
+    /// This takes `&mut self`, so the generator cannot be resumed again while a
+    /// resume is still running. Reentrant resumes are ruled out at compile time.
+    ///
     /// [_See the module-level docs for examples._](.)
     pub fn resume_with(&mut self, arg: R) -> GeneratorState<Y, F::Output> {
+        assert!(
+            !self.poisoned,
+            "generator resumed after it yielded an error"
+        );
         self.airlock.replace(Next::Resume(arg));
-        advance(self.future.as_mut(), &self.airlock)
+        let state = advance(self.future.as_mut(), &self.airlock);
+        self.update_done(&state);
+        state
+    }
+
+    /// Resumes execution of the generator up to `n` times, with a clone of
+    /// `arg` each time.
+    ///
+    /// Returns the values which were yielded. If the generator completed within
+    /// the budget, the `Complete` state is returned too, otherwise `None` is.
+    /// A generator which is already done is not resumed at all.
+    ///
+    /// [_See the module-level docs for examples._](.)
+    pub fn resume_n(
+        &mut self,
+        arg: R,
+        n: usize,
+    ) -> (Vec<Y>, Option<GeneratorState<Y, F::Output>>)
+    where
+        R: Clone,
+    {
+        let mut values = Vec::new();
+        for _ in 0..n {
+            if self.done {
+                break;
+            }
+            match self.resume_with(arg.clone()) {
+                GeneratorState::Yielded(y) => values.push(y),
+                state @ GeneratorState::Complete(_) => return (values, Some(state)),
+            }
+        }
+        (values, None)
+    }
+
+    /// Resumes execution of the generator until it yields a value which matches
+    /// `pred`, or until it completes.
+    ///
+    /// Each resume argument is pulled from `arg_supplier`. Yielded values which
+    /// do not match are dropped. Once the generator completes, `Complete` is
+    /// returned right away, so `arg_supplier` is never called after that.
+    ///
+    /// Returns `None` if the generator was already done, in which case it is
+    /// not resumed at all, and `arg_supplier` is not called.
+    ///
+    /// [_See the module-level docs for examples._](.)
+    pub fn advance_until(
+        &mut self,
+        mut arg_supplier: impl FnMut() -> R,
+        mut pred: impl FnMut(&Y) -> bool,
+    ) -> Option<GeneratorState<Y, F::Output>> {
+        if self.done {
+            return None;
+        }
+        loop {
+            match self.resume_with(arg_supplier()) {
+                GeneratorState::Yielded(value) => {
+                    if pred(&value) {
+                        return Some(GeneratorState::Yielded(value));
+                    }
+                }
+                state @ GeneratorState::Complete(_) => return Some(state),
+            }
+        }
+    }
+
+    /// Returns `true` if the generator has completed.
+    ///
+    /// This only inspects a flag which is set once the generator returns
+    /// `Complete`, or once [`try_resume`](#method.try_resume) returns an error,
+    /// so it never polls the underlying future.
+    ///
+    /// [_See the module-level docs for examples._](.)
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Takes the return value which was left behind when the generator
+    /// completed while it was being iterated through `&mut`.
+    ///
+    /// Returns `None` if the generator has not completed that way, or if the
+    /// value was already taken.
+    ///
+    /// [_See the module-level docs for examples._](.)
+    pub fn take_return(&mut self) -> Option<F::Output> {
+        self.return_value.take()
+    }
+
+    /// Replaces the future in place with a fresh one from `producer`, so the
+    /// generator starts over from the beginning.
+    pub(crate) fn reset_with(&mut self, producer: impl FnOnce(Co<Y, R>) -> F) {
+        // `Pin::set` drops the old future in place, so the allocation is reused
+        // without the pinned value ever being moved.
+        self.future.set(producer(Co::new(self.airlock.clone())));
+        self.airlock.replace(Next::Empty);
+        self.done = false;
+        self.poisoned = false;
+        self.return_value = None;
+    }
+
+    /// Keeps the return value, for when the generator completed while it was
+    /// being iterated through `&mut`.
+    pub(crate) fn store_return(&mut self, value: F::Output) {
+        self.return_value = Some(value);
+    }
+
+    fn update_done(&mut self, state: &GeneratorState<Y, F::Output>) {
+        if let GeneratorState::Complete(_) = state {
+            self.done = true;
+        }
     }
 }
 
@@ -70,10 +208,267 @@ impl<Y, F: Future> Gen<Y, (), F> {
         &mut self,
     ) -> impl Future<Output = GeneratorState<Y, F::Output>> + '_ {
         self.airlock.replace(Next::Resume(()));
-        async_advance(self.future.as_mut(), self.airlock.clone())
+        async move {
+            let state = async_advance(self.future.as_mut(), self.airlock.clone()).await;
+            self.update_done(&state);
+            state
+        }
+    }
+
+    /// Runs the generator to completion, calling `f` with each yielded value.
+    ///
+    /// Returns the generator's return value.
+    ///
+    /// [_See the module-level docs for examples._](.)
+    pub fn for_each(mut self, mut f: impl FnMut(Y)) -> F::Output {
+        loop {
+            match self.resume() {
+                GeneratorState::Yielded(value) => f(value),
+                GeneratorState::Complete(value) => return value,
+            }
+        }
+    }
+
+    /// Runs the generator to completion, counting the yielded values.
+    ///
+    /// Returns the count, along with the generator's return value.
+    ///
+    /// [_See the module-level docs for examples._](.)
+    #[must_use]
+    pub fn count(self) -> (usize, F::Output) {
+        let mut count = 0;
+        let value = self.for_each(|_| count += 1);
+        (count, value)
+    }
+
+    /// Runs the generator to completion, keeping only the last yielded value.
+    ///
+    /// Returns the last value, or `None` if nothing was yielded, along with the
+    /// generator's return value.
+    ///
+    /// [_See the module-level docs for examples._](.)
+    #[must_use]
+    pub fn last(self) -> (Option<Y>, F::Output) {
+        let mut last = None;
+        let value = self.for_each(|y| last = Some(y));
+        (last, value)
+    }
+
+    /// Runs the generator to completion, and returns the first yielded value
+    /// which matches `pred`.
+    ///
+    /// Unlike `Iterator::find`, the generator is still run to the end after a
+    /// match, so that its return value can be returned too. `pred` is not
+    /// called again after it matches, and the remaining values are dropped.
+    ///
+    /// [_See the module-level docs for examples._](.)
+    pub fn find(self, mut pred: impl FnMut(&Y) -> bool) -> (Option<Y>, F::Output) {
+        let mut found = None;
+        let value = self.for_each(|y| {
+            if found.is_none() && pred(&y) {
+                found = Some(y);
+            }
+        });
+        (found, value)
+    }
+
+    /// Runs the generator to completion, and returns the index of the first
+    /// yielded value which matches `pred`.
+    ///
+    /// As with [`find`](#method.find), the generator is still run to the end
+    /// after a match, and its return value is returned too.
+    ///
+    /// [_See the module-level docs for examples._](.)
+    pub fn position(
+        self,
+        mut pred: impl FnMut(&Y) -> bool,
+    ) -> (Option<usize>, F::Output) {
+        let mut index = 0;
+        let mut found = None;
+        let value = self.for_each(|y| {
+            if found.is_none() && pred(&y) {
+                found = Some(index);
+            }
+            index += 1;
+        });
+        (found, value)
+    }
+
+    /// Runs the generator to completion, and returns the yielded value for
+    /// which `f` returns the largest key.
+    ///
+    /// As with `Iterator::max_by_key`, the last of several equal values is
+    /// returned. The generator's return value is returned too.
+    ///
+    /// [_See the module-level docs for examples._](.)
+    pub fn max_by_key<K: Ord>(
+        self,
+        mut f: impl FnMut(&Y) -> K,
+    ) -> (Option<Y>, F::Output) {
+        let mut max: Option<(K, Y)> = None;
+        let value = self.for_each(|y| {
+            let key = f(&y);
+            match &max {
+                Some((max_key, _)) if key < *max_key => {}
+                _ => max = Some((key, y)),
+            }
+        });
+        (max.map(|(_, y)| y), value)
+    }
+
+    /// Runs the generator to completion, and returns the yielded value for
+    /// which `f` returns the smallest key.
+    ///
+    /// As with `Iterator::min_by_key`, the first of several equal values is
+    /// returned. The generator's return value is returned too.
+    ///
+    /// [_See the module-level docs for examples._](.)
+    pub fn min_by_key<K: Ord>(
+        self,
+        mut f: impl FnMut(&Y) -> K,
+    ) -> (Option<Y>, F::Output) {
+        let mut min: Option<(K, Y)> = None;
+        let value = self.for_each(|y| {
+            let key = f(&y);
+            match &min {
+                Some((min_key, _)) if key >= *min_key => {}
+                _ => min = Some((key, y)),
+            }
+        });
+        (min.map(|(_, y)| y), value)
+    }
+
+    /// Runs the generator to completion, splitting the yielded values into two
+    /// collections.
+    ///
+    /// Values for which `pred` returns `true` go into the first collection, and
+    /// the rest go into the second. Returns both, along with the generator's
+    /// return value.
+    ///
+    /// [_See the module-level docs for examples._](.)
+    pub fn partition<B>(self, mut pred: impl FnMut(&Y) -> bool) -> (B, B, F::Output)
+    where
+        B: Default + Extend<Y>,
+    {
+        let mut matching = B::default();
+        let mut rest = B::default();
+        let value = self.for_each(|y| {
+            if pred(&y) {
+                matching.extend(Some(y));
+            } else {
+                rest.extend(Some(y));
+            }
+        });
+        (matching, rest, value)
+    }
+
+    /// Runs the generator to completion, grouping the yielded values by key.
+    ///
+    /// Each value is passed to `key`, and then converted with `val` and pushed
+    /// onto the `Vec` for that key, so values keep the order they were yielded
+    /// in. Returns the map, along with the generator's return value.
+    ///
+    /// This requires the `std` feature.
+    ///
+    /// [_See the module-level docs for examples._](.)
+    #[cfg(feature = "std")]
+    pub fn group_by_key<K, V>(
+        self,
+        mut key: impl FnMut(&Y) -> K,
+        mut val: impl FnMut(Y) -> V,
+    ) -> (HashMap<K, Vec<V>>, F::Output)
+    where
+        K: Eq + Hash,
+    {
+        let mut groups = HashMap::new();
+        let value = self.for_each(|y| {
+            groups.entry(key(&y)).or_insert_with(Vec::new).push(val(y));
+        });
+        (groups, value)
+    }
+
+    /// Runs the generator to completion, folding every yielded value into an
+    /// accumulator.
+    ///
+    /// Returns the accumulator, along with the generator's return value.
+    ///
+    /// [_See the module-level docs for examples._](.)
+    pub fn fold<Acc>(
+        mut self,
+        init: Acc,
+        mut f: impl FnMut(Acc, Y) -> Acc,
+    ) -> (Acc, F::Output) {
+        let mut acc = init;
+        loop {
+            match self.resume() {
+                GeneratorState::Yielded(value) => acc = f(acc, value),
+                GeneratorState::Complete(value) => return (acc, value),
+            }
+        }
+    }
+
+    /// Runs the generator to completion, folding every yielded value into an
+    /// accumulator, and short-circuits if `f` fails.
+    ///
+    /// Returns the accumulator, along with the generator's return value.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from `f`. The generator is not resumed again
+    /// after that.
+    ///
+    /// [_See the module-level docs for examples._](.)
+    pub fn try_fold<Acc, E>(
+        mut self,
+        init: Acc,
+        mut f: impl FnMut(Acc, Y) -> Result<Acc, E>,
+    ) -> Result<(Acc, F::Output), E> {
+        let mut acc = init;
+        loop {
+            match self.resume() {
+                GeneratorState::Yielded(value) => acc = f(acc, value)?,
+                GeneratorState::Complete(value) => return Ok((acc, value)),
+            }
+        }
     }
 }
 
+impl<T, E, R, F: Future> Gen<Result<T, E>, R, F> {
+    /// Resumes execution of the generator, and short-circuits if it yields an
+    /// error.
+    ///
+    /// A yielded `Ok(value)` is returned as `Ok(Yielded(value))`, and
+    /// completion as `Ok(Complete(value))`. A yielded `Err(error)` is returned
+    /// as `Err(error)`, and the generator is then considered done, so it is
+    /// never polled again.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error if the generator yields `Err`.
+    ///
+    /// # Panics
+    ///
+    /// Once an error has been returned, calling this method or
+    /// [`resume_with`](#method.resume_with) again panics, rather than polling
+    /// the generator past the error.
+    ///
+    /// [_See the module-level docs for examples._](.)
+    pub fn try_resume(&mut self, arg: R) -> Result<GeneratorState<T, F::Output>, E> {
+        match self.resume_with(arg) {
+            GeneratorState::Yielded(Ok(value)) => Ok(GeneratorState::Yielded(value)),
+            GeneratorState::Yielded(Err(error)) => {
+                self.done = true;
+                self.poisoned = true;
+                Err(error)
+            }
+            GeneratorState::Complete(value) => Ok(GeneratorState::Complete(value)),
+        }
+    }
+}
+
+// The future is behind a `Box`, so it stays put even if the generator moves.
+impl<Y, R, F: Future> Unpin for Gen<Y, R, F> {}
+
 impl<Y, R, F: Future> Coroutine for Gen<Y, R, F> {
     type Yield = Y;
     type Resume = R;
@@ -86,3 +481,21 @@ impl<Y, R, F: Future> Coroutine for Gen<Y, R, F> {
         Self::resume_with(&mut *self, arg)
     }
 }
+
+impl<Y, R, F, P> IntoCoroutine<Co<Y, R>> for P
+where
+    F: Future,
+    P: FnOnce(Co<Y, R>) -> F,
+{
+    type Coroutine = Gen<Y, R, F>;
+
+    fn into_coroutine(self) -> Self::Coroutine {
+        Gen::new(self)
+    }
+}
+
+impl<Y, R, F: Future> fmt::Debug for Gen<Y, R, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Gen").field("done", &self.done).finish()
+    }
+}
@@ -1,35 +1,180 @@
 use crate::{ops::GeneratorState, rc::Gen};
-use std::future::Future;
+use core::{
+    future::Future,
+    iter::{FromIterator, FusedIterator},
+};
 
-impl<Y, F: Future<Output = ()>> IntoIterator for Gen<Y, (), F> {
+impl<Y, F: Future> IntoIterator for Gen<Y, (), F> {
     type Item = Y;
     type IntoIter = IntoIter<Y, F>;
 
     #[must_use]
     fn into_iter(self) -> Self::IntoIter {
-        IntoIter { generator: self }
+        IntoIter {
+            generator: self,
+            return_value: None,
+        }
     }
 }
 
-pub struct IntoIter<Y, F: Future<Output = ()>> {
+/// An iterator which resumes a generator until it completes.
+///
+/// This is created by `Gen`'s `IntoIterator` impl. Once the generator
+/// completes, its return value is kept, and can be read with
+/// [`return_value`](#method.return_value).
+pub struct IntoIter<Y, F: Future> {
     generator: Gen<Y, (), F>,
+    return_value: Option<F::Output>,
+}
+
+impl<Y, F: Future> IntoIter<Y, F> {
+    /// Returns the generator's return value, once iteration has finished.
+    ///
+    /// Returns `None` if the generator has not completed yet, for example if
+    /// the caller stopped iterating early.
+    #[must_use]
+    pub fn return_value(&self) -> Option<&F::Output> {
+        self.return_value.as_ref()
+    }
+
+    /// Collects every remaining value, and returns them along with the
+    /// generator's return value.
+    pub fn collect_with_return<B: FromIterator<Y>>(mut self) -> (B, Option<F::Output>) {
+        let items = self.by_ref().collect();
+        (items, self.return_value)
+    }
 }
 
-impl<Y, F: Future<Output = ()>> Iterator for IntoIter<Y, F> {
+impl<Y, F: Future> Iterator for IntoIter<Y, F> {
     type Item = Y;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.generator.is_done() {
+            return None;
+        }
         match self.generator.resume() {
             GeneratorState::Yielded(x) => Some(x),
-            GeneratorState::Complete(()) => None,
+            GeneratorState::Complete(value) => {
+                self.return_value = Some(value);
+                None
+            }
+        }
+    }
+}
+
+// `next` checks `is_done` before resuming, so the future is never polled again
+// once it has completed.
+impl<Y, F: Future> FusedIterator for IntoIter<Y, F> {}
+
+impl<'r, Y, F: Future> IntoIterator for &'r mut Gen<Y, (), F> {
+    type Item = Y;
+    type IntoIter = MutIntoIter<'r, Y, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<Y, F: Future> Gen<Y, (), F> {
+    /// Creates an iterator which resumes the generator through a `&mut`, so the
+    /// generator is left in place once iteration stops.
+    ///
+    /// This is the same as iterating over `&mut gen`.
+    pub fn iter_mut(&mut self) -> MutIntoIter<'_, Y, F> {
+        MutIntoIter { generator: self }
+    }
+}
+
+/// An iterator which resumes a generator through a `&mut`, leaving it in place.
+///
+/// Once the generator completes, its return value is kept in the generator,
+/// and can be taken with [`Gen::take_return`](struct.Gen.html#method.take_return).
+pub struct MutIntoIter<'r, Y, F: Future> {
+    generator: &'r mut Gen<Y, (), F>,
+}
+
+impl<Y, F: Future> Iterator for MutIntoIter<'_, Y, F> {
+    type Item = Y;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.generator.is_done() {
+            return None;
+        }
+        match self.generator.resume() {
+            GeneratorState::Yielded(x) => Some(x),
+            GeneratorState::Complete(value) => {
+                self.generator.store_return(value);
+                None
+            }
+        }
+    }
+}
+
+impl<Y, F: Future> FusedIterator for MutIntoIter<'_, Y, F> {}
+
+impl<Y, R, F: Future> Gen<Y, R, F> {
+    /// Creates an iterator which resumes the generator with arguments from
+    /// `supplier`.
+    ///
+    /// `supplier` is called once before each value is pulled from the
+    /// generator, and its result is passed as the resume argument. (As usual,
+    /// the first resume argument is not observed by the generator.) Once the
+    /// generator completes, its return value is available from
+    /// [`into_return`](struct.IterResumeWith.html#method.into_return).
+    pub fn iter_resume_with<S>(self, supplier: S) -> IterResumeWith<Y, R, F, S>
+    where
+        S: FnMut() -> R,
+    {
+        IterResumeWith {
+            generator: self,
+            supplier,
+            return_value: None,
+        }
+    }
+}
+
+/// An iterator which resumes a generator with arguments from a supplier.
+///
+/// This is created by
+/// [`Gen::iter_resume_with`](struct.Gen.html#method.iter_resume_with).
+pub struct IterResumeWith<Y, R, F: Future, S> {
+    generator: Gen<Y, R, F>,
+    supplier: S,
+    return_value: Option<F::Output>,
+}
+
+impl<Y, R, F: Future, S> IterResumeWith<Y, R, F, S> {
+    /// Consumes the iterator, and returns the generator's return value.
+    ///
+    /// Returns `None` if the generator has not completed yet.
+    pub fn into_return(self) -> Option<F::Output> {
+        self.return_value
+    }
+}
+
+impl<Y, R, F: Future, S: FnMut() -> R> Iterator for IterResumeWith<Y, R, F, S> {
+    type Item = Y;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.generator.is_done() {
+            return None;
+        }
+        match self.generator.resume_with((self.supplier)()) {
+            GeneratorState::Yielded(x) => Some(x),
+            GeneratorState::Complete(value) => {
+                self.return_value = Some(value);
+                None
+            }
         }
     }
 }
 
+impl<Y, R, F: Future, S: FnMut() -> R> FusedIterator for IterResumeWith<Y, R, F, S> {}
+
 #[cfg(test)]
 mod tests {
     use crate::rc::{Co, Gen};
-    use std::iter::IntoIterator;
+    use std::iter::{FusedIterator, IntoIterator};
 
     async fn produce(mut co: Co<i32>) {
         co.yield_(10).await;
@@ -43,6 +188,99 @@ mod tests {
         assert_eq!(items, [10, 20]);
     }
 
+    #[test]
+    fn iterators_are_fused() {
+        fn assert_fused<I: FusedIterator>(_: &I) {}
+
+        let mut iter = Gen::new(produce).into_iter();
+        assert_fused(&iter);
+        assert_eq!(iter.by_ref().count(), 2);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+
+        assert_fused(&Gen::new(produce).iter_resume_with(|| ()));
+    }
+
+    #[test]
+    fn return_value_after_full_drain() {
+        let gen = Gen::new(|mut co| {
+            async move {
+                co.yield_(1).await;
+                co.yield_(2).await;
+                "rest"
+            }
+        });
+        let mut iter = gen.into_iter();
+        assert_eq!(iter.by_ref().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(iter.return_value(), Some(&"rest"));
+    }
+
+    #[test]
+    fn return_value_after_early_stop() {
+        let gen = Gen::new(|mut co| {
+            async move {
+                co.yield_(1).await;
+                co.yield_(2).await;
+                "rest"
+            }
+        });
+        let mut iter = gen.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.return_value(), None);
+    }
+
+    #[test]
+    fn collect_with_return() {
+        let gen = Gen::new(|mut co| {
+            async move {
+                co.yield_(1).await;
+                co.yield_(2).await;
+                "rest"
+            }
+        });
+        let (items, rest): (Vec<_>, _) = gen.into_iter().collect_with_return();
+        assert_eq!(items, [1, 2]);
+        assert_eq!(rest, Some("rest"));
+    }
+
+    #[test]
+    fn iter_resume_with() {
+        async fn running_sum(mut co: Co<i32, i32>) -> &'static str {
+            let mut sum = 0;
+            for _ in 0..3 {
+                sum += co.yield_(sum).await;
+            }
+            "done"
+        }
+
+        let mut inputs = 1..;
+        let mut iter =
+            Gen::new(running_sum).iter_resume_with(|| inputs.next().unwrap());
+        assert_eq!(iter.by_ref().collect::<Vec<_>>(), [0, 2, 5]);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.into_return(), Some("done"));
+    }
+
+    #[test]
+    fn mut_for_loop() {
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                co.yield_(10).await;
+                co.yield_(20).await;
+                "done"
+            }
+        });
+        let mut items = Vec::new();
+        for x in &mut gen {
+            items.push(x);
+        }
+        assert_eq!(items, [10, 20]);
+        assert!(gen.is_done());
+        assert_eq!(gen.take_return(), Some("done"));
+        assert_eq!(gen.take_return(), None);
+        assert_eq!(gen.iter_mut().next(), None);
+    }
+
     #[test]
     fn for_loop() {
         let mut sum = 0;
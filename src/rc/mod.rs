@@ -280,7 +280,17 @@ assert_eq!(gen.resume(), GeneratorState::Yielded(30));
 ```
 */
 
-pub use crate::rc::{engine::Co, generator::Gen};
+pub use crate::{
+    ops::drive_with,
+    rc::{
+        boxed::GenBoxed,
+        engine::{Airlock, Co},
+        fuse::FusedGen,
+        generator::Gen,
+        iterator::{IntoIter, IterResumeWith, MutIntoIter},
+        resettable::{Cycle, ResettableGen},
+    },
+};
 
 /// Creates a generator.
 ///
@@ -304,9 +314,15 @@ pub use genawaiter_macro::rc_gen as gen;
 #[cfg(feature = "proc_macro")]
 pub use genawaiter_proc_macro::rc_producer_fn as producer_fn;
 
+mod boxed;
 mod engine;
+mod from_iter;
+mod fuse;
 mod generator;
 mod iterator;
+mod poll_fn;
+mod repeat_with;
+mod resettable;
 #[cfg(feature = "futures03")]
 mod stream;
 
@@ -319,11 +335,14 @@ mod tests {
     use crate::{
         rc::{Co, Gen},
         testing::DummyFuture,
+        Airlock,
         GeneratorState,
+        Next,
     };
     use std::{
         cell::{Cell, RefCell},
         future::Future,
+        panic::{self, AssertUnwindSafe},
     };
 
     async fn simple_producer(mut co: Co<i32>) -> &'static str {
@@ -350,6 +369,336 @@ mod tests {
         assert_eq!(gen.resume(), GeneratorState::Complete("done"));
     }
 
+    #[test]
+    fn is_done() {
+        let mut gen = Gen::new(simple_producer);
+        assert!(!gen.is_done());
+        assert_eq!(gen.resume(), GeneratorState::Yielded(10));
+        assert!(!gen.is_done());
+        assert_eq!(gen.resume(), GeneratorState::Complete("done"));
+        assert!(gen.is_done());
+    }
+
+    #[test]
+    fn debug() {
+        let mut gen = Gen::new(simple_producer);
+        assert_eq!(format!("{:?}", gen), "Gen { done: false }");
+        assert_eq!(format!("{:?}", gen.resume()), "Yielded(10)");
+        assert_eq!(format!("{:?}", gen), "Gen { done: false }");
+        assert_eq!(format!("{:?}", gen.resume()), "Complete(\"done\")");
+        assert_eq!(format!("{:?}", gen), "Gen { done: true }");
+    }
+
+    #[test]
+    fn for_each() {
+        let mut xs = Vec::new();
+        let value = Gen::new(|mut co| {
+            async move {
+                co.yield_(1).await;
+                co.yield_(2).await;
+                "done"
+            }
+        })
+        .for_each(|x| xs.push(x));
+        assert_eq!(xs, [1, 2]);
+        assert_eq!(value, "done");
+    }
+
+    #[test]
+    fn count() {
+        let gen = Gen::new(|mut co| {
+            async move {
+                for i in 0..3 {
+                    co.yield_(i).await;
+                }
+                "done"
+            }
+        });
+        assert_eq!(gen.count(), (3, "done"));
+    }
+
+    #[test]
+    fn airlock() {
+        let mut gen = Gen::new(|mut co: Co<i32, i32>| {
+            async move {
+                // The first resume argument is never taken by a `yield_`.
+                assert_eq!(co.airlock().peek(), Next::Resume(()));
+                let n = co.yield_(10).await;
+                assert_eq!(co.airlock().peek(), Next::Empty);
+                n
+            }
+        });
+        assert_eq!(gen.resume_with(0), GeneratorState::Yielded(10));
+        assert_eq!(gen.resume_with(5), GeneratorState::Complete(5));
+    }
+
+    async fn one_to_five(mut co: Co<i32>) -> &'static str {
+        for i in 1..=5 {
+            co.yield_(i).await;
+        }
+        "done"
+    }
+
+    #[test]
+    fn fold() {
+        let gen = Gen::new(one_to_five);
+        assert_eq!(gen.fold(0, |acc, n| acc + n), (15, "done"));
+    }
+
+    #[test]
+    fn try_fold() {
+        let gen = Gen::new(one_to_five);
+        assert_eq!(
+            gen.try_fold(0, |acc, n| Ok::<_, ()>(acc + n)),
+            Ok((15, "done")),
+        );
+
+        let seen = Cell::new(0);
+        let gen = Gen::new(one_to_five);
+        let result = gen.try_fold(0, |acc, n| {
+            seen.set(n);
+            if n == 3 {
+                Err(acc)
+            } else {
+                Ok(acc + n)
+            }
+        });
+        assert_eq!(result, Err(3));
+        assert_eq!(seen.get(), 3);
+    }
+
+    #[test]
+    fn partition() {
+        let gen = Gen::new(|mut co| {
+            async move {
+                for i in 0..10 {
+                    co.yield_(i).await;
+                }
+                "done"
+            }
+        });
+        let (evens, odds, value): (Vec<_>, Vec<_>, _) = gen.partition(|n| n % 2 == 0);
+        assert_eq!(evens, [0, 2, 4, 6, 8]);
+        assert_eq!(odds, [1, 3, 5, 7, 9]);
+        assert_eq!(value, "done");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn group_by_key() {
+        let gen = Gen::new(|mut co| {
+            async move {
+                co.yield_(("fruit", "apple")).await;
+                co.yield_(("veg", "leek")).await;
+                co.yield_(("fruit", "pear")).await;
+                "done"
+            }
+        });
+        let (groups, value) =
+            gen.group_by_key(|&(category, _)| category, |(_, item)| item);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups["fruit"], ["apple", "pear"]);
+        assert_eq!(groups["veg"], ["leek"]);
+        assert_eq!(value, "done");
+    }
+
+    #[test]
+    fn drop_mid_yield_runs_destructors() {
+        struct SetOnDrop<'a>(&'a Cell<bool>);
+
+        impl Drop for SetOnDrop<'_> {
+            fn drop(&mut self) {
+                self.0.set(true);
+            }
+        }
+
+        let dropped = &Cell::new(false);
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                let _guard = SetOnDrop(dropped);
+                co.yield_(1).await;
+                co.yield_(2).await;
+            }
+        });
+        assert_eq!(gen.resume(), GeneratorState::Yielded(1));
+        assert!(!dropped.get());
+        drop(gen);
+        assert!(dropped.get());
+    }
+
+    async fn odds_then_even(mut co: Co<i32>) -> &'static str {
+        co.yield_all(vec![1, 3, 5, 6, 7]).await;
+        "done"
+    }
+
+    #[test]
+    fn find() {
+        let gen = Gen::new(odds_then_even);
+        assert_eq!(gen.find(|n| n % 2 == 0), (Some(6), "done"));
+        let gen = Gen::new(odds_then_even);
+        assert_eq!(gen.find(|&n| n > 10), (None, "done"));
+    }
+
+    #[test]
+    fn position() {
+        let gen = Gen::new(odds_then_even);
+        assert_eq!(gen.position(|n| n % 2 == 0), (Some(3), "done"));
+        let gen = Gen::new(odds_then_even);
+        assert_eq!(gen.position(|&n| n > 10), (None, "done"));
+    }
+
+    #[test]
+    fn max_and_min_by_key() {
+        #[derive(PartialEq, Debug)]
+        struct City {
+            name: &'static str,
+            population: u32,
+        }
+
+        async fn cities(mut co: Co<City>) -> &'static str {
+            let all = vec![("a", 5), ("b", 9), ("c", 2), ("d", 9), ("e", 2)];
+            for (name, population) in all {
+                co.yield_(City { name, population }).await;
+            }
+            "done"
+        }
+
+        let (max, value) = Gen::new(cities).max_by_key(|city| city.population);
+        assert_eq!(max.unwrap().name, "d");
+        assert_eq!(value, "done");
+        let (min, value) = Gen::new(cities).min_by_key(|city| city.population);
+        assert_eq!(min.unwrap().name, "c");
+        assert_eq!(value, "done");
+
+        let empty = Gen::new(|_: Co<City>| async { "done" });
+        assert_eq!(empty.max_by_key(|city| city.population), (None, "done"));
+    }
+
+    #[test]
+    fn advance_until() {
+        let mut gen = Gen::new(|mut co: Co<i32, i32>| {
+            async move {
+                let mut n = 0;
+                for _ in 0..5 {
+                    n += co.yield_(n).await;
+                }
+                "done"
+            }
+        });
+        let calls = Cell::new(0);
+        let mut supplier = || {
+            calls.set(calls.get() + 1);
+            3
+        };
+        assert_eq!(
+            gen.advance_until(&mut supplier, |&n| n > 4),
+            Some(GeneratorState::Yielded(6)),
+        );
+        assert_eq!(calls.get(), 3);
+        assert_eq!(
+            gen.advance_until(&mut supplier, |&n| n > 100),
+            Some(GeneratorState::Complete("done")),
+        );
+        assert_eq!(calls.get(), 6);
+        assert_eq!(gen.advance_until(&mut supplier, |_| true), None);
+        assert_eq!(calls.get(), 6);
+    }
+
+    #[test]
+    fn drive_with() {
+        async fn running_total(mut co: Co<i32, i32>) -> i32 {
+            let mut total = 0;
+            for _ in 0..3 {
+                total += co.yield_(total).await;
+            }
+            total
+        }
+
+        // The first input is not observed, so only `2` and `3` are added.
+        let (values, value) = super::drive_with(Gen::new(running_total), vec![1, 2, 3]);
+        assert_eq!(values, [0, 2, 5]);
+        assert_eq!(value, None);
+
+        let (values, value) = super::drive_with(Gen::new(running_total), 1..);
+        assert_eq!(values, [0, 2, 5]);
+        assert_eq!(value, Some(9));
+    }
+
+    #[test]
+    fn last() {
+        let gen = Gen::new(|mut co| {
+            async move {
+                for i in 1..5 {
+                    co.yield_(i).await;
+                }
+                "done"
+            }
+        });
+        assert_eq!(gen.last(), (Some(4), "done"));
+
+        let gen = Gen::new(|_: Co<i32>| async move { "done" });
+        assert_eq!(gen.last(), (None, "done"));
+    }
+
+    #[test]
+    fn resume_n_stops_at_budget() {
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                for i in 0..5 {
+                    co.yield_(i).await;
+                }
+                "done"
+            }
+        });
+        assert_eq!(gen.resume_n((), 2), (vec![0, 1], None));
+        assert_eq!(gen.resume_n((), 2), (vec![2, 3], None));
+        assert_eq!(
+            gen.resume_n((), 2),
+            (vec![4], Some(GeneratorState::Complete("done"))),
+        );
+        assert_eq!(gen.resume_n((), 2), (vec![], None));
+    }
+
+    #[test]
+    fn try_resume_stops_at_error() {
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                co.yield_(Ok(1)).await;
+                co.yield_(Err("bad")).await;
+                co.yield_(Ok(2)).await;
+            }
+        });
+        assert_eq!(gen.try_resume(()), Ok(GeneratorState::Yielded(1)));
+        assert_eq!(gen.try_resume(()), Err("bad"));
+        assert!(gen.is_done());
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| gen.try_resume(())));
+        let payload = result.unwrap_err();
+        assert_eq!(
+            payload.downcast_ref::<&str>(),
+            Some(&"generator resumed after it yielded an error"),
+        );
+    }
+
+    #[test]
+    fn try_resume_completes() {
+        fn run() -> Result<Vec<i32>, &'static str> {
+            let mut gen = Gen::new(|mut co| {
+                async move {
+                    co.yield_(Ok(1)).await;
+                    co.yield_(Ok(2)).await;
+                    Ok::<_, &str>(())
+                }
+            });
+            let mut xs = Vec::new();
+            while let GeneratorState::Yielded(x) = gen.try_resume(())? {
+                xs.push(x);
+            }
+            Ok(xs)
+        }
+        assert_eq!(run(), Ok(vec![1, 2]));
+    }
+
     #[test]
     fn resume_args() {
         async fn gen(resumes: &RefCell<Vec<&str>>, mut co: Co<i32, &'static str>) {
@@ -373,6 +722,103 @@ mod tests {
         assert_eq!(*resumes.borrow(), &["abc", "def"]);
     }
 
+    #[test]
+    fn yield_all() {
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                co.yield_all(vec![10, 20]).await;
+            }
+        });
+        assert_eq!(gen.resume(), GeneratorState::Yielded(10));
+        assert_eq!(gen.resume(), GeneratorState::Yielded(20));
+        assert_eq!(gen.resume(), GeneratorState::Complete(()));
+    }
+
+    #[test]
+    fn yield_all_with() {
+        let mut gen = Gen::new(|mut co: Co<i32, &'static str>| {
+            async move { co.yield_all_with(vec![10, 20]).await }
+        });
+        assert_eq!(gen.resume_with("ignored"), GeneratorState::Yielded(10));
+        assert_eq!(gen.resume_with("abc"), GeneratorState::Yielded(20));
+        assert_eq!(
+            gen.resume_with("def"),
+            GeneratorState::Complete(vec!["abc", "def"]),
+        );
+    }
+
+    #[test]
+    fn yield_while() {
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                let count = Cell::new(0);
+                co.yield_while(
+                    || count.get() < 5,
+                    || {
+                        count.set(count.get() + 1);
+                        count.get()
+                    },
+                )
+                .await;
+                count.get()
+            }
+        });
+        for i in 1..=5 {
+            assert_eq!(gen.resume(), GeneratorState::Yielded(i));
+        }
+        assert_eq!(gen.resume(), GeneratorState::Complete(5));
+    }
+
+    #[test]
+    fn yield_while_with() {
+        let mut gen = Gen::new(|mut co: Co<i32, &'static str>| {
+            async move {
+                let count = Cell::new(0);
+                co.yield_while_with(
+                    || count.get() < 20,
+                    || {
+                        count.set(count.get() + 10);
+                        count.get()
+                    },
+                )
+                .await
+            }
+        });
+        assert_eq!(gen.resume_with("ignored"), GeneratorState::Yielded(10));
+        assert_eq!(gen.resume_with("abc"), GeneratorState::Yielded(20));
+        assert_eq!(
+            gen.resume_with("def"),
+            GeneratorState::Complete(vec!["abc", "def"]),
+        );
+    }
+
+    #[test]
+    fn yield_from() {
+        async fn inner(mut co: Co<i32, i32>) -> i32 {
+            let x = co.yield_(1).await;
+            let y = co.yield_(x + 100).await;
+            y + 1000
+        }
+
+        async fn middle(mut co: Co<i32, i32>) -> i32 {
+            let x = co.yield_(0).await;
+            let ret = co.yield_from(Gen::new(inner)).await;
+            let y = co.yield_(ret + x).await;
+            x + y
+        }
+
+        async fn outer(mut co: Co<i32, i32>) -> i32 {
+            co.yield_from(Gen::new(middle)).await * 2
+        }
+
+        let mut gen = Gen::new(outer);
+        assert_eq!(gen.resume_with(0), GeneratorState::Yielded(0));
+        assert_eq!(gen.resume_with(5), GeneratorState::Yielded(1));
+        assert_eq!(gen.resume_with(7), GeneratorState::Yielded(107));
+        assert_eq!(gen.resume_with(9), GeneratorState::Yielded(1014));
+        assert_eq!(gen.resume_with(11), GeneratorState::Complete(32));
+    }
+
     #[test]
     #[should_panic(expected = "non-async method")]
     fn forbidden_await_helpful_message() {
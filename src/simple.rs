@@ -0,0 +1,151 @@
+/*!
+This module implements a coroutine which is driven by a plain closure, without
+any futures.
+
+A [`GenMut`] calls its closure once per resume. The closure gets the resume
+argument, and says whether to yield or complete by returning a
+[`GeneratorState`]. Any state it needs lives in the closure's captures, so this
+suits small hand-written state machines, where an `async` producer would be
+more machinery than needed.
+
+```rust
+use genawaiter::{simple::GenMut, GeneratorState};
+
+let mut n = 0;
+let mut counter = GenMut::new(move |()| {
+    n += 1;
+    if n <= 2 {
+        GeneratorState::Yielded(n)
+    } else {
+        GeneratorState::Complete("done")
+    }
+});
+assert_eq!(counter.resume(), GeneratorState::Yielded(1));
+assert_eq!(counter.resume(), GeneratorState::Yielded(2));
+assert_eq!(counter.resume(), GeneratorState::Complete("done"));
+```
+
+Since `GenMut` implements [`Coroutine`], it works with all the
+adapters.
+*/
+
+use crate::ops::{Coroutine, GeneratorState};
+use core::{marker::PhantomData, pin::Pin};
+
+/// A coroutine which calls a closure each time it is resumed.
+///
+/// Unlike the other generators in this crate, the closure is called again even
+/// after it returns `Complete`, so it is up to the closure what happens then.
+///
+/// [_See the module-level docs for examples._](.)
+pub struct GenMut<Y, R, Ret, F> {
+    step: F,
+    _marker: PhantomData<fn(R) -> (Y, Ret)>,
+}
+
+impl<Y, R, Ret, F: FnMut(R) -> GeneratorState<Y, Ret>> GenMut<Y, R, Ret, F> {
+    /// Creates a new coroutine from a closure.
+    ///
+    /// The closure is called with each resume argument, and its result is
+    /// returned from the resume.
+    pub fn new(step: F) -> Self {
+        Self {
+            step,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Resumes the execution of this coroutine.
+    ///
+    /// `arg` is passed to the closure, and whatever it returns is returned.
+    pub fn resume_with(&mut self, arg: R) -> GeneratorState<Y, Ret> {
+        (self.step)(arg)
+    }
+}
+
+impl<Y, Ret, F: FnMut(()) -> GeneratorState<Y, Ret>> GenMut<Y, (), Ret, F> {
+    /// Resumes the execution of this coroutine.
+    pub fn resume(&mut self) -> GeneratorState<Y, Ret> {
+        self.resume_with(())
+    }
+}
+
+// The closure is never pinned, so it can be moved freely.
+impl<Y, R, Ret, F> Unpin for GenMut<Y, R, Ret, F> {}
+
+impl<Y, R, Ret, F: FnMut(R) -> GeneratorState<Y, Ret>> Coroutine
+    for GenMut<Y, R, Ret, F>
+{
+    type Yield = Y;
+    type Resume = R;
+    type Return = Ret;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: R,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        self.get_mut().resume_with(arg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, GeneratorState},
+        simple::GenMut,
+    };
+    use std::pin::Pin;
+
+    fn counter(
+        limit: u32,
+    ) -> GenMut<
+        u32,
+        (),
+        &'static str,
+        impl FnMut(()) -> GeneratorState<u32, &'static str>,
+    > {
+        let mut n = 0;
+        GenMut::new(move |()| {
+            if n < limit {
+                n += 1;
+                GeneratorState::Yielded(n)
+            } else {
+                GeneratorState::Complete("done")
+            }
+        })
+    }
+
+    #[test]
+    fn counts() {
+        let mut gen = counter(2);
+        assert_eq!(gen.resume(), GeneratorState::Yielded(1));
+        assert_eq!(gen.resume(), GeneratorState::Yielded(2));
+        assert_eq!(gen.resume(), GeneratorState::Complete("done"));
+    }
+
+    #[test]
+    fn resume_arg() {
+        let mut total = 0;
+        let mut gen = GenMut::new(move |x: i32| {
+            total += x;
+            GeneratorState::<i32, ()>::Yielded(total)
+        });
+        assert_eq!(gen.resume_with(1), GeneratorState::Yielded(1));
+        assert_eq!(gen.resume_with(2), GeneratorState::Yielded(3));
+    }
+
+    #[test]
+    fn is_unpin_coroutine() {
+        fn assert_unpin_coroutine<C: Coroutine<Resume = ()> + Unpin>(_: &C) {}
+
+        let mut gen = counter(3);
+        assert_unpin_coroutine(&gen);
+        assert_eq!(
+            Pin::new(&mut gen).resume_with(()),
+            GeneratorState::Yielded(1)
+        );
+
+        let xs: Vec<_> = gen.map_yield(|x| x * 10).into_iter().collect();
+        assert_eq!(xs, [20, 30]);
+    }
+}
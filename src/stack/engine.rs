@@ -1,5 +1,5 @@
 use crate::{core, core::Next};
-use std::{cell::UnsafeCell, ptr};
+use ::core::{cell::UnsafeCell, ptr};
 
 /// This type holds the value that is pending being returned from the generator.
 ///
@@ -16,6 +16,8 @@ impl<Y, R> Default for Airlock<Y, R> {
     }
 }
 
+impl<Y, R> core::sealed::Sealed for &Airlock<Y, R> {}
+
 impl<'s, Y, R> core::Airlock for &'s Airlock<Y, R> {
     type Yield = Y;
     type Resume = R;
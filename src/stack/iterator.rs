@@ -1,5 +1,5 @@
 use crate::{ops::GeneratorState, stack::generator::Gen};
-use std::future::Future;
+use core::future::Future;
 
 impl<'s, Y, F: Future<Output = ()>> IntoIterator for Gen<'s, Y, (), F> {
     type Item = Y;
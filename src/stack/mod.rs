@@ -267,6 +267,12 @@ pub use crate::stack::{
 /// The second argument is the body of the generator. It should contain one or
 /// more calls to the [`yield_!`] macro.
 ///
+/// If the resume type cannot be inferred, it can be given before the body:
+///
+/// ```ignore
+/// let_gen!(my_generator, resume = i32, { /* ... */ });
+/// ```
+///
 /// This macro is a shortcut for creating both a generator and its backing state
 /// (called a [`Shelf`](struct.Shelf.html)). If you (or your IDE) dislike
 /// macros, you can also do the bookkeeping by hand by using
@@ -304,6 +310,12 @@ pub use genawaiter_macro::stack_let_gen as let_gen;
 ///     fn producer(co: Co<'_, Yield, Resume>) -> impl Future<Output = Completion> { /* ... */ }
 ///     ```
 ///
+/// If the resume type cannot be inferred, it can be given before the producer:
+///
+/// ```ignore
+/// let_gen_using!(my_generator, resume = i32, |co| async move { /* ... */ });
+/// ```
+///
 /// This macro is a shortcut for creating both a generator and its backing state
 /// (called a [`Shelf`](struct.Shelf.html)). If you (or your IDE) dislike
 /// macros, you can also do the bookkeeping by hand by using
@@ -346,7 +358,7 @@ mod tests {
         GeneratorState,
     };
     use std::{
-        cell::RefCell,
+        cell::{Cell, RefCell},
         sync::{
             atomic::{AtomicBool, Ordering},
             Arc,
@@ -400,6 +412,92 @@ mod tests {
         assert_eq!(*resumes.borrow(), &["abc", "def"]);
     }
 
+    #[test]
+    fn yield_all() {
+        let_gen_using!(gen, |mut co| {
+            async move {
+                co.yield_all(vec![10, 20]).await;
+            }
+        });
+        assert_eq!(gen.resume(), GeneratorState::Yielded(10));
+        assert_eq!(gen.resume(), GeneratorState::Yielded(20));
+        assert_eq!(gen.resume(), GeneratorState::Complete(()));
+    }
+
+    #[test]
+    fn yield_all_with() {
+        let_gen_using!(gen, |mut co: Co<'_, i32, &'static str>| {
+            async move { co.yield_all_with(vec![10, 20]).await }
+        });
+        assert_eq!(gen.resume_with("ignored"), GeneratorState::Yielded(10));
+        assert_eq!(gen.resume_with("abc"), GeneratorState::Yielded(20));
+        assert_eq!(
+            gen.resume_with("def"),
+            GeneratorState::Complete(vec!["abc", "def"]),
+        );
+    }
+
+    #[test]
+    fn resume_type() {
+        let_gen_using!(gen, resume = i32, |mut co| {
+            async move {
+                let mut sum = 0;
+                loop {
+                    sum += co.yield_(sum).await;
+                }
+            }
+        });
+        assert_eq!(gen.resume_with(100), GeneratorState::Yielded(0));
+        assert_eq!(gen.resume_with(1), GeneratorState::Yielded(1));
+        assert_eq!(gen.resume_with(2), GeneratorState::Yielded(3));
+        assert_eq!(gen.resume_with(3), GeneratorState::Yielded(6));
+    }
+
+    #[test]
+    fn yield_while() {
+        let_gen_using!(gen, |mut co| {
+            async move {
+                let count = Cell::new(0);
+                co.yield_while(
+                    || count.get() < 5,
+                    || {
+                        count.set(count.get() + 1);
+                        count.get()
+                    },
+                )
+                .await;
+                count.get()
+            }
+        });
+        for i in 1..=5 {
+            assert_eq!(gen.resume(), GeneratorState::Yielded(i));
+        }
+        assert_eq!(gen.resume(), GeneratorState::Complete(5));
+    }
+
+    #[test]
+    fn yield_while_with() {
+        let_gen_using!(gen, |mut co: Co<'_, i32, &'static str>| {
+            async move {
+                let count = Cell::new(0);
+                co.yield_while_with(
+                    || count.get() < 20,
+                    || {
+                        count.set(count.get() + 10);
+                        count.get()
+                    },
+                )
+                .await
+            }
+        });
+        assert_eq!(gen.resume_with("ignored"), GeneratorState::Yielded(10));
+        assert_eq!(gen.resume_with("abc"), GeneratorState::Yielded(20));
+        assert_eq!(
+            gen.resume_with("def"),
+            GeneratorState::Complete(vec!["abc", "def"]),
+        );
+    }
+
     #[test]
     #[should_panic(expected = "non-async method")]
     fn forbidden_await_helpful_message() {
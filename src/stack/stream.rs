@@ -1,11 +1,11 @@
 use crate::{ops::GeneratorState, stack::Gen};
+use core::{future::Future, pin::Pin};
 use futures_core::{
     task::{Context, Poll},
     Stream,
 };
-use std::{future::Future, pin::Pin};
 
-impl<'s, Y, F: Future<Output = ()>> Stream for Gen<'s, Y, (), F> {
+impl<'s, Y, F: Future> Stream for Gen<'s, Y, (), F> {
     type Item = Y;
 
     fn poll_next(
@@ -16,7 +16,7 @@ impl<'s, Y, F: Future<Output = ()>> Stream for Gen<'s, Y, (), F> {
         pin_mut!(fut);
         match fut.poll(cx) {
             Poll::Ready(GeneratorState::Yielded(x)) => Poll::Ready(Some(x)),
-            Poll::Ready(GeneratorState::Complete(())) => Poll::Ready(None),
+            Poll::Ready(GeneratorState::Complete(_)) => Poll::Ready(None),
             Poll::Pending => Poll::Pending,
         }
     }
@@ -28,7 +28,11 @@ mod tests {
         stack::{let_gen_using, Co},
         testing::SlowFuture,
     };
-    use futures::{executor::block_on_stream, stream};
+    use futures::{
+        executor::{block_on, block_on_stream},
+        stream,
+        StreamExt,
+    };
 
     #[test]
     fn blocking() {
@@ -56,4 +60,18 @@ mod tests {
         let items: Vec<_> = block_on_stream(gen).collect();
         assert_eq!(items, [10, 20]);
     }
+
+    #[test]
+    fn collect() {
+        async fn produce(mut co: Co<'_, i32>) -> &'static str {
+            co.yield_(10).await;
+            SlowFuture::new().await;
+            co.yield_(20).await;
+            "done"
+        }
+
+        let_gen_using!(gen, produce);
+        let items: Vec<_> = block_on(gen.collect());
+        assert_eq!(items, [10, 20]);
+    }
 }
@@ -1,5 +1,6 @@
 use crate::sync::{Co, Gen};
-use std::{future::Future, pin::Pin};
+use alloc::boxed::Box;
+use core::{any::Any, future::Future, pin::Pin};
 
 /// This is a type alias for generators which can be stored in a `'static`. It's
 /// only really needed to help the compiler's type inference along.
@@ -37,13 +38,42 @@ impl<Y, R, C> GenBoxed<Y, R, C> {
     }
 }
 
+impl<R> Co<Box<dyn Any>, R> {
+    /// Boxes a value, and yields it from the generator.
+    ///
+    /// This lets a generator yield values of several concrete types through a
+    /// single `Box<dyn Any>`. The caller can get them back with `downcast`. As
+    /// with any use of `Any`, `T` must be `'static`, so it cannot hold any
+    /// non-`'static` references.
+    ///
+    /// The caller should immediately `await` the result of this function.
+    pub async fn yield_boxed<T: Any>(&mut self, value: T) -> R {
+        self.yield_(Box::new(value)).await
+    }
+}
+
+impl<R> Co<Box<dyn Any + Send>, R> {
+    /// Boxes a value, and yields it from the generator.
+    ///
+    /// This is the same as the method for `Box<dyn Any>` above, for generators
+    /// whose yielded values must be `Send`.
+    ///
+    /// The caller should immediately `await` the result of this function.
+    pub async fn yield_boxed<T: Any + Send>(&mut self, value: T) -> R {
+        self.yield_(Box::new(value)).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         ops::GeneratorState,
         sync::{Co, Gen},
     };
-    use std::sync::{Arc, Mutex};
+    use std::{
+        any::Any,
+        sync::{Arc, Mutex},
+    };
 
     async fn odd_numbers_less_than_ten(mut co: Co<i32>) {
         for n in (1..).step_by(2).take_while(|&n| n < 10) {
@@ -68,4 +98,34 @@ mod tests {
         assert_eq!(guard.resume(), GeneratorState::Yielded(1));
         assert_eq!(guard.resume(), GeneratorState::Yielded(3));
     }
+
+    #[test]
+    fn yield_boxed() {
+        let mut gen = Gen::new(|mut co: Co<Box<dyn Any>>| {
+            async move {
+                co.yield_boxed(10_i32).await;
+                co.yield_boxed("ten").await;
+            }
+        });
+        let first = gen.resume().yielded().unwrap();
+        assert_eq!(first.downcast_ref::<i32>(), Some(&10));
+        let second = gen.resume().yielded().unwrap();
+        assert_eq!(second.downcast_ref::<&str>(), Some(&"ten"));
+        assert!(second.downcast_ref::<i32>().is_none());
+        assert_eq!(gen.resume().complete(), Some(()));
+    }
+
+    #[test]
+    fn yield_boxed_send() {
+        let mut gen = Gen::new(|mut co: Co<Box<dyn Any + Send>, i32>| {
+            async move {
+                let n = co.yield_boxed(vec![1, 2]).await;
+                co.yield_boxed(n).await;
+            }
+        });
+        let first = gen.resume_with(0).yielded().unwrap();
+        assert_eq!(first.downcast_ref::<Vec<i32>>(), Some(&vec![1, 2]));
+        let second = gen.resume_with(5).yielded().unwrap();
+        assert_eq!(second.downcast_ref::<i32>(), Some(&5));
+    }
 }
@@ -1,14 +1,23 @@
 use crate::{core, core::Next};
-use std::{
-    mem,
-    sync::{Arc, Mutex},
+use ::core::mem;
+#[cfg(not(feature = "std"))]
+use ::core::{
+    cell::UnsafeCell,
+    sync::atomic::{self, AtomicBool, Ordering},
 };
+use alloc::sync::Arc;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, PoisonError};
 
-pub struct Airlock<Y, R>(Arc<Mutex<Next<Y, R>>>);
+/// The slot through which values pass between the generator and its caller.
+///
+/// This is shared between threads, so each access takes a lock: a `Mutex` with
+/// `std`, or a small spin lock without it.
+pub struct Airlock<Y, R>(Arc<Lock<Next<Y, R>>>);
 
 impl<Y, R> Default for Airlock<Y, R> {
     fn default() -> Self {
-        Self(Arc::new(Mutex::new(Next::Empty)))
+        Self(Arc::new(Lock::new(Next::Empty)))
     }
 }
 
@@ -18,16 +27,82 @@ impl<Y, R> Clone for Airlock<Y, R> {
     }
 }
 
+impl<Y, R> core::sealed::Sealed for Airlock<Y, R> {}
+
 impl<Y, R> core::Airlock for Airlock<Y, R> {
     type Yield = Y;
     type Resume = R;
 
     fn peek(&self) -> Next<(), ()> {
-        self.0.lock().unwrap().without_values()
+        self.0.with(|next| next.without_values())
     }
 
     fn replace(&self, next: Next<Y, R>) -> Next<Y, R> {
-        mem::replace(&mut self.0.lock().unwrap(), next)
+        self.0.with(|old| mem::replace(old, next))
+    }
+}
+
+/// With `std`, the airlock is a plain `Mutex`.
+///
+/// The mutex is only held for the duration of a `peek` or `replace`, neither of
+/// which can panic, so it is never poisoned in practice. If it somehow is, the
+/// value is still consistent, so the poison is ignored.
+#[cfg(feature = "std")]
+struct Lock<T>(Mutex<T>);
+
+#[cfg(feature = "std")]
+impl<T> Lock<T> {
+    fn new(value: T) -> Self {
+        Self(Mutex::new(value))
+    }
+
+    fn with<U>(&self, f: impl FnOnce(&mut T) -> U) -> U {
+        let mut guard = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        f(&mut guard)
+    }
+}
+
+/// Without `std` there is no `Mutex`, so this is a minimal spin lock instead.
+///
+/// The lock is only held for the duration of a `peek` or `replace`, so in
+/// practice it is never contended.
+#[cfg(not(feature = "std"))]
+struct Lock<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+// Safety: Access to `value` is serialized by `locked`, the same as `Mutex<T>`.
+#[cfg(not(feature = "std"))]
+unsafe impl<T: Send> Send for Lock<T> {}
+#[cfg(not(feature = "std"))]
+unsafe impl<T: Send> Sync for Lock<T> {}
+
+#[cfg(not(feature = "std"))]
+impl<T> Lock<T> {
+    fn new(value: T) -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    fn with<U>(&self, f: impl FnOnce(&mut T) -> U) -> U {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            // `core::hint::spin_loop` replaces this, but only from Rust 1.49.
+            #[allow(deprecated)]
+            atomic::spin_loop_hint();
+        }
+        // Safety: The lock is held, so no other reference to the value exists.
+        // `f` is only ever `without_values` or `mem::replace`, neither of which
+        // can panic, so the lock is always released.
+        let result = f(unsafe { &mut *self.value.get() });
+        self.locked.store(false, Ordering::Release);
+        result
     }
 }
 
@@ -39,3 +114,14 @@ impl<Y, R> core::Airlock for Airlock<Y, R> {
 ///
 /// [_See the module-level docs for examples._](.)
 pub type Co<Y, R = ()> = core::Co<Airlock<Y, R>>;
+
+impl<Y, R> Co<Y, R> {
+    /// Returns the airlock which this generator uses to pass values to and from
+    /// its caller.
+    ///
+    /// This is meant for debugging and for custom drivers. See
+    /// [`Airlock`](crate::Airlock) for the steps the airlock goes through.
+    pub fn airlock(&self) -> &Airlock<Y, R> {
+        &self.airlock
+    }
+}
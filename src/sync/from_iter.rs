@@ -0,0 +1,83 @@
+use crate::sync::{Co, Gen};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+impl<I: Iterator> Gen<I::Item, (), FromIterFuture<I>> {
+    /// Creates a generator which yields each value from an iterator.
+    ///
+    /// This is the reverse of `into_iter`, and lets ordinary iterators be used
+    /// wherever a coroutine is expected.
+    ///
+    /// [_See the module-level docs for examples._](.)
+    // This can't be a `FromIterator` impl, since the type of the generator
+    // depends on the type of the iterator.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_iter<T: IntoIterator<IntoIter = I>>(iter: T) -> Self {
+        let iter = iter.into_iter();
+        Self::new(|co| FromIterFuture { iter, co })
+    }
+}
+
+/// The future inside a generator created by
+/// [`Gen::from_iter`](struct.Gen.html#method.from_iter).
+///
+/// Each poll takes one value from the iterator and yields it, so no async block
+/// (and no state machine) is needed.
+pub struct FromIterFuture<I: Iterator> {
+    iter: I,
+    co: Co<I::Item>,
+}
+
+// No field is structurally pinned.
+impl<I: Iterator> Unpin for FromIterFuture<I> {}
+
+impl<I: Iterator> Future for FromIterFuture<I> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        match this.iter.next() {
+            Some(value) => {
+                // `yield_` stores the value as soon as it's called. The future it
+                // returns only waits for the next resume, which is the same as
+                // this future being polled again, so it can be dropped.
+                drop(this.co.yield_(value));
+                Poll::Pending
+            }
+            None => Poll::Ready(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, GeneratorState},
+        sync::Gen,
+    };
+
+    #[test]
+    fn round_trip() {
+        let gen = Gen::from_iter(vec![1, 2, 3]);
+        let xs: Vec<_> = gen.into_iter().collect();
+        assert_eq!(xs, [1, 2, 3]);
+    }
+
+    #[test]
+    fn completes_with_unit() {
+        let mut gen = Gen::from_iter(Some("a"));
+        assert_eq!(gen.resume(), GeneratorState::Yielded("a"));
+        assert_eq!(gen.resume(), GeneratorState::Complete(()));
+        assert!(gen.is_done());
+    }
+
+    #[test]
+    fn works_with_adapters() {
+        let gen = Gen::from_iter(1..=2).chain(Gen::from_iter(vec![3]));
+        let xs: Vec<_> = gen.into_iter().collect();
+        assert_eq!(xs, [1, 2, 3]);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{
+    ops::{Coroutine, GeneratorState},
+    sync::Gen,
+};
+use core::{future::Future, pin::Pin};
+
+/// A generator which can safely be resumed after it completes.
+///
+/// This is created by [`Gen::fuse`](struct.Gen.html#method.fuse). It mirrors
+/// [`std::iter::Fuse`].
+///
+/// The first time the generator completes, its return value is handed out as
+/// `Complete(Some(value))`. Every later resume returns `Complete(None)`,
+/// without polling the underlying future again. The return value is moved out
+/// exactly once, so this works the same whether or not it is `Clone`.
+///
+/// [_See the module-level docs for examples._](.)
+pub struct FusedGen<Y, R, F: Future> {
+    generator: Gen<Y, R, F>,
+}
+
+impl<Y, R, F: Future> Gen<Y, R, F> {
+    /// Creates a generator which can safely be resumed after it completes.
+    ///
+    /// [_See the docs for `FusedGen` for details._](struct.FusedGen.html)
+    #[must_use]
+    pub fn fuse(self) -> FusedGen<Y, R, F> {
+        FusedGen { generator: self }
+    }
+}
+
+impl<Y, R, F: Future> FusedGen<Y, R, F> {
+    /// Resumes execution of the generator.
+    ///
+    /// If the generator yields a value, `Yielded` is returned. If it completes,
+    /// `Complete(Some(value))` is returned. If it had already completed,
+    /// `Complete(None)` is returned.
+    pub fn resume_with(&mut self, arg: R) -> GeneratorState<Y, Option<F::Output>> {
+        if self.generator.is_done() {
+            return GeneratorState::Complete(None);
+        }
+        match self.generator.resume_with(arg) {
+            GeneratorState::Yielded(y) => GeneratorState::Yielded(y),
+            GeneratorState::Complete(value) => GeneratorState::Complete(Some(value)),
+        }
+    }
+
+    /// Returns `true` if the generator has completed.
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        self.generator.is_done()
+    }
+}
+
+impl<Y, F: Future> FusedGen<Y, (), F> {
+    /// Resumes execution of the generator.
+    ///
+    /// If the generator yields a value, `Yielded` is returned. If it completes,
+    /// `Complete(Some(value))` is returned. If it had already completed,
+    /// `Complete(None)` is returned.
+    pub fn resume(&mut self) -> GeneratorState<Y, Option<F::Output>> {
+        self.resume_with(())
+    }
+}
+
+impl<Y, R, F: Future> Coroutine for FusedGen<Y, R, F> {
+    type Yield = Y;
+    type Resume = R;
+    type Return = Option<F::Output>;
+
+    fn resume_with(
+        mut self: Pin<&mut Self>,
+        arg: R,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        Self::resume_with(&mut *self, arg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::GeneratorState,
+        sync::{Co, Gen},
+    };
+
+    #[derive(Debug, PartialEq)]
+    struct NotClone(i32);
+
+    async fn produce(mut co: Co<i32>) -> NotClone {
+        co.yield_(10).await;
+        NotClone(20)
+    }
+
+    #[test]
+    fn resume_after_complete() {
+        let mut gen = Gen::new(produce).fuse();
+        assert_eq!(gen.resume(), GeneratorState::Yielded(10));
+        assert_eq!(gen.resume(), GeneratorState::Complete(Some(NotClone(20))));
+        assert!(gen.is_done());
+        // Resuming an `async fn` after completion would normally panic.
+        assert_eq!(gen.resume(), GeneratorState::Complete(None));
+        assert_eq!(gen.resume(), GeneratorState::Complete(None));
+    }
+}
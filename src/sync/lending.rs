@@ -0,0 +1,162 @@
+use crate::{ops::GeneratorState, sync::Gen};
+use core::future::Future;
+
+/// A generator which lends each yielded value to the caller, and then hands it
+/// back to the generator.
+///
+/// `resume_with` returns a reference to the yielded value, which is valid until
+/// the next resume. When the generator is resumed, it gets the value back from
+/// `LendingCo::yield_`, so it can reuse the value's allocation (e.g. clear and
+/// refill a buffer) instead of making a new one for each yield.
+///
+/// ```rust
+/// use genawaiter::{
+///     sync::{LendingCo, LendingGen},
+///     GeneratorState,
+/// };
+///
+/// async fn lines(input: &str, mut co: LendingCo<String>) {
+///     let mut buf = String::new();
+///     for line in input.lines() {
+///         buf.clear();
+///         buf.push_str(line.trim());
+///         let (returned, ()) = co.yield_(buf).await;
+///         buf = returned;
+///     }
+/// }
+///
+/// let mut gen = LendingGen::new(|co| lines(" a \n b ", co));
+/// assert_eq!(gen.resume(), GeneratorState::Yielded(&"a".to_string()));
+/// assert_eq!(gen.resume(), GeneratorState::Yielded(&"b".to_string()));
+/// assert_eq!(gen.resume(), GeneratorState::Complete(()));
+/// ```
+pub struct LendingGen<Y, R, F: Future> {
+    generator: Gen<Y, (Option<Y>, R), F>,
+    lent: Option<Y>,
+}
+
+/// This object lets you lend values from a [`LendingGen`] by calling the
+/// `yield_` method.
+pub struct LendingCo<Y, R = ()> {
+    co: crate::sync::Co<Y, (Option<Y>, R)>,
+}
+
+impl<Y, R> LendingCo<Y, R> {
+    /// Lends a value to the caller of the generator.
+    ///
+    /// Once the generator is resumed, the value is returned, along with the
+    /// resume argument.
+    ///
+    /// The caller should immediately `await` the result of this function.
+    pub async fn yield_(&mut self, value: Y) -> (Y, R) {
+        match self.co.yield_(value).await {
+            (Some(lent), arg) => (lent, arg),
+            // `LendingGen` stores every yielded value, and always hands it back
+            // on the following resume.
+            (None, _) => unreachable!(),
+        }
+    }
+}
+
+impl<Y, R, F: Future> LendingGen<Y, R, F> {
+    /// Creates a new lending generator from a function.
+    ///
+    /// This works like [`Gen::new`](struct.Gen.html#method.new), except that
+    /// the function receives a [`LendingCo`].
+    pub fn new(producer: impl FnOnce(LendingCo<Y, R>) -> F) -> Self {
+        Self {
+            generator: Gen::new(|co| producer(LendingCo { co })),
+            lent: None,
+        }
+    }
+
+    /// Resumes execution of the generator.
+    ///
+    /// If the generator yields a value, a reference to it is returned, which is
+    /// valid until the next resume. Otherwise, `Complete` is returned.
+    pub fn resume_with(&mut self, arg: R) -> GeneratorState<&Y, F::Output> {
+        let lent = self.lent.take();
+        match self.generator.resume_with((lent, arg)) {
+            GeneratorState::Yielded(value) => {
+                GeneratorState::Yielded(&*self.lent.get_or_insert(value))
+            }
+            GeneratorState::Complete(value) => GeneratorState::Complete(value),
+        }
+    }
+
+    /// Returns `true` if the generator has completed.
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        self.generator.is_done()
+    }
+}
+
+impl<Y, F: Future> LendingGen<Y, (), F> {
+    /// Resumes execution of the generator.
+    ///
+    /// If the generator yields a value, a reference to it is returned, which is
+    /// valid until the next resume. Otherwise, `Complete` is returned.
+    pub fn resume(&mut self) -> GeneratorState<&Y, F::Output> {
+        self.resume_with(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        sync::{LendingCo, LendingGen},
+        GeneratorState,
+    };
+
+    async fn chunks(input: &[u8], mut co: LendingCo<Vec<u8>, usize>) -> usize {
+        let mut buf = Vec::with_capacity(4);
+        let mut rest = input;
+        let mut count = 0;
+        while !rest.is_empty() {
+            buf.clear();
+            let len = rest.len().min(buf.capacity());
+            buf.extend_from_slice(&rest[..len]);
+            rest = &rest[len..];
+            count += 1;
+            let (returned, _) = co.yield_(buf).await;
+            buf = returned;
+        }
+        count
+    }
+
+    #[test]
+    fn lends_and_reuses_value() {
+        let input = b"abcdefghij";
+        let mut gen = LendingGen::new(|co| chunks(input, co));
+
+        let first = match gen.resume_with(0) {
+            GeneratorState::Yielded(buf) => {
+                assert_eq!(buf, b"abcd");
+                buf.as_ptr()
+            }
+            GeneratorState::Complete(_) => panic!(),
+        };
+        match gen.resume_with(0) {
+            GeneratorState::Yielded(buf) => {
+                assert_eq!(buf, b"efgh");
+                assert_eq!(buf.as_ptr(), first);
+            }
+            GeneratorState::Complete(_) => panic!(),
+        }
+        assert_eq!(gen.resume_with(0), GeneratorState::Yielded(&b"ij".to_vec()));
+        assert_eq!(gen.resume_with(0), GeneratorState::Complete(3));
+        assert!(gen.is_done());
+    }
+
+    #[test]
+    fn passes_resume_arguments() {
+        let mut gen = LendingGen::new(|mut co: LendingCo<i32, &'static str>| {
+            async move {
+                let (_, arg) = co.yield_(1).await;
+                arg
+            }
+        });
+        assert_eq!(gen.resume_with("ignored"), GeneratorState::Yielded(&1));
+        assert_eq!(gen.resume_with("abc"), GeneratorState::Complete("abc"));
+    }
+}
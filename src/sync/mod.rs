@@ -312,7 +312,25 @@ assert_eq!(gen.resume(), GeneratorState::Yielded(30));
 ```
 */
 
-pub use crate::sync::{boxed::GenBoxed, engine::Co, generator::Gen};
+#[cfg(feature = "futures03")]
+pub use crate::sync::stream::GenStream;
+#[cfg(all(feature = "futures03", feature = "std"))]
+pub use crate::sync::timeout::TimedOut;
+#[cfg(feature = "std")]
+pub use crate::sync::{par::par_collect, shared::SharedGen};
+pub use crate::{
+    ops::drive_with,
+    sync::{
+        boxed::GenBoxed,
+        engine::{Airlock, Co},
+        fuse::FusedGen,
+        generator::Gen,
+        iterator::{IntoIter, IterResumeWith, MutIntoIter},
+        lending::{LendingCo, LendingGen},
+        resettable::{Cycle, ResettableGen},
+        sink::GenSink,
+    },
+};
 
 /// Creates a generator.
 ///
@@ -338,10 +356,23 @@ pub use genawaiter_proc_macro::sync_producer_fn as producer_fn;
 
 mod boxed;
 mod engine;
+mod from_iter;
+mod fuse;
 mod generator;
 mod iterator;
+mod lending;
+#[cfg(feature = "std")]
+mod par;
+mod poll_fn;
+mod repeat_with;
+mod resettable;
+#[cfg(feature = "std")]
+mod shared;
+mod sink;
 #[cfg(feature = "futures03")]
 mod stream;
+#[cfg(all(feature = "futures03", feature = "std"))]
+mod timeout;
 
 #[cfg(feature = "nightly")]
 #[cfg(test)]
@@ -350,14 +381,20 @@ mod nightly_tests;
 #[cfg(test)]
 mod tests {
     use crate::{
-        sync::{Co, Gen},
+        sync::{self, Co, Gen},
         testing::{DummyFuture, SlowFuture},
+        Airlock,
         GeneratorState,
+        Next,
     };
-    use futures::executor::block_on;
+    use futures::{channel::oneshot, executor::block_on, future};
     use std::{
         cell::{Cell, RefCell},
         future::Future,
+        panic::{self, AssertUnwindSafe},
+        pin::Pin,
+        thread,
+        time::Duration,
     };
 
     async fn simple_producer(mut co: Co<i32>) -> &'static str {
@@ -384,6 +421,404 @@ mod tests {
         assert_eq!(gen.resume(), GeneratorState::Complete("done"));
     }
 
+    #[test]
+    fn is_done() {
+        let mut gen = Gen::new(simple_producer);
+        assert!(!gen.is_done());
+        assert_eq!(gen.resume(), GeneratorState::Yielded(10));
+        assert!(!gen.is_done());
+        assert_eq!(gen.resume(), GeneratorState::Complete("done"));
+        assert!(gen.is_done());
+    }
+
+    #[test]
+    fn debug() {
+        let mut gen = Gen::new(simple_producer);
+        assert_eq!(format!("{:?}", gen), "Gen { done: false }");
+        assert_eq!(format!("{:?}", gen.resume()), "Yielded(10)");
+        assert_eq!(format!("{:?}", gen), "Gen { done: false }");
+        assert_eq!(format!("{:?}", gen.resume()), "Complete(\"done\")");
+        assert_eq!(format!("{:?}", gen), "Gen { done: true }");
+    }
+
+    #[test]
+    fn for_each() {
+        let mut xs = Vec::new();
+        let value = Gen::new(|mut co| {
+            async move {
+                co.yield_(1).await;
+                co.yield_(2).await;
+                "done"
+            }
+        })
+        .for_each(|x| xs.push(x));
+        assert_eq!(xs, [1, 2]);
+        assert_eq!(value, "done");
+    }
+
+    #[test]
+    fn count() {
+        let gen = Gen::new(|mut co| {
+            async move {
+                for i in 0..3 {
+                    co.yield_(i).await;
+                }
+                "done"
+            }
+        });
+        assert_eq!(gen.count(), (3, "done"));
+    }
+
+    #[test]
+    fn airlock() {
+        let mut gen = Gen::new(|mut co: Co<i32, i32>| {
+            async move {
+                // The first resume argument is never taken by a `yield_`.
+                assert_eq!(co.airlock().peek(), Next::Resume(()));
+                let n = co.yield_(10).await;
+                assert_eq!(co.airlock().peek(), Next::Empty);
+                n
+            }
+        });
+        assert_eq!(gen.resume_with(0), GeneratorState::Yielded(10));
+        assert_eq!(gen.resume_with(5), GeneratorState::Complete(5));
+    }
+
+    async fn one_to_five(mut co: Co<i32>) -> &'static str {
+        for i in 1..=5 {
+            co.yield_(i).await;
+        }
+        "done"
+    }
+
+    #[test]
+    fn fold() {
+        let gen = Gen::new(one_to_five);
+        assert_eq!(gen.fold(0, |acc, n| acc + n), (15, "done"));
+    }
+
+    #[test]
+    fn try_fold() {
+        let gen = Gen::new(one_to_five);
+        assert_eq!(
+            gen.try_fold(0, |acc, n| Ok::<_, ()>(acc + n)),
+            Ok((15, "done")),
+        );
+
+        let seen = Cell::new(0);
+        let gen = Gen::new(one_to_five);
+        let result = gen.try_fold(0, |acc, n| {
+            seen.set(n);
+            if n == 3 {
+                Err(acc)
+            } else {
+                Ok(acc + n)
+            }
+        });
+        assert_eq!(result, Err(3));
+        assert_eq!(seen.get(), 3);
+    }
+
+    #[test]
+    fn partition() {
+        let gen = Gen::new(|mut co| {
+            async move {
+                for i in 0..10 {
+                    co.yield_(i).await;
+                }
+                "done"
+            }
+        });
+        let (evens, odds, value): (Vec<_>, Vec<_>, _) = gen.partition(|n| n % 2 == 0);
+        assert_eq!(evens, [0, 2, 4, 6, 8]);
+        assert_eq!(odds, [1, 3, 5, 7, 9]);
+        assert_eq!(value, "done");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn group_by_key() {
+        let gen = Gen::new(|mut co| {
+            async move {
+                co.yield_(("fruit", "apple")).await;
+                co.yield_(("veg", "leek")).await;
+                co.yield_(("fruit", "pear")).await;
+                "done"
+            }
+        });
+        let (groups, value) =
+            gen.group_by_key(|&(category, _)| category, |(_, item)| item);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups["fruit"], ["apple", "pear"]);
+        assert_eq!(groups["veg"], ["leek"]);
+        assert_eq!(value, "done");
+    }
+
+    #[test]
+    fn drop_mid_yield_runs_destructors() {
+        struct SetOnDrop<'a>(&'a Cell<bool>);
+
+        impl Drop for SetOnDrop<'_> {
+            fn drop(&mut self) {
+                self.0.set(true);
+            }
+        }
+
+        let dropped = &Cell::new(false);
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                let _guard = SetOnDrop(dropped);
+                co.yield_(1).await;
+                co.yield_(2).await;
+            }
+        });
+        assert_eq!(gen.resume(), GeneratorState::Yielded(1));
+        assert!(!dropped.get());
+        drop(gen);
+        assert!(dropped.get());
+    }
+
+    async fn odds_then_even(mut co: Co<i32>) -> &'static str {
+        co.yield_all(vec![1, 3, 5, 6, 7]).await;
+        "done"
+    }
+
+    #[test]
+    fn find() {
+        let gen = Gen::new(odds_then_even);
+        assert_eq!(gen.find(|n| n % 2 == 0), (Some(6), "done"));
+        let gen = Gen::new(odds_then_even);
+        assert_eq!(gen.find(|&n| n > 10), (None, "done"));
+    }
+
+    #[test]
+    fn position() {
+        let gen = Gen::new(odds_then_even);
+        assert_eq!(gen.position(|n| n % 2 == 0), (Some(3), "done"));
+        let gen = Gen::new(odds_then_even);
+        assert_eq!(gen.position(|&n| n > 10), (None, "done"));
+    }
+
+    #[test]
+    fn max_and_min_by_key() {
+        #[derive(PartialEq, Debug)]
+        struct City {
+            name: &'static str,
+            population: u32,
+        }
+
+        async fn cities(mut co: Co<City>) -> &'static str {
+            let all = vec![("a", 5), ("b", 9), ("c", 2), ("d", 9), ("e", 2)];
+            for (name, population) in all {
+                co.yield_(City { name, population }).await;
+            }
+            "done"
+        }
+
+        let (max, value) = Gen::new(cities).max_by_key(|city| city.population);
+        assert_eq!(max.unwrap().name, "d");
+        assert_eq!(value, "done");
+        let (min, value) = Gen::new(cities).min_by_key(|city| city.population);
+        assert_eq!(min.unwrap().name, "c");
+        assert_eq!(value, "done");
+
+        let empty = Gen::new(|_: Co<City>| async { "done" });
+        assert_eq!(empty.max_by_key(|city| city.population), (None, "done"));
+    }
+
+    #[test]
+    fn into_parts_and_back() {
+        async fn produce(mut co: Co<i32, i32>) -> i32 {
+            let mut total = 0;
+            for n in 1..=4 {
+                total += co.yield_(n).await;
+            }
+            total
+        }
+
+        let mut gen = Gen::new(produce);
+        assert_eq!(gen.resume_with(0), GeneratorState::Yielded(1));
+        assert_eq!(gen.resume_with(10), GeneratorState::Yielded(2));
+
+        let (future, airlock, done, poisoned) = gen.into_parts();
+        assert!(!done && !poisoned);
+        let handle = thread::spawn(move || {
+            let mut gen = Gen::from_parts(future, airlock, done, poisoned);
+            assert_eq!(gen.resume_with(20), GeneratorState::Yielded(3));
+            gen
+        });
+        let mut gen = handle.join().unwrap();
+        assert_eq!(gen.resume_with(30), GeneratorState::Yielded(4));
+        assert_eq!(gen.resume_with(40), GeneratorState::Complete(100));
+        assert!(gen.is_done());
+
+        let (future, airlock, done, poisoned) = gen.into_parts();
+        assert!(done && !poisoned);
+        assert!(Gen::from_parts(future, airlock, done, poisoned).is_done());
+    }
+
+    #[test]
+    fn into_parts_keeps_poison() {
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                co.yield_(Err("bad")).await;
+                co.yield_(Ok(1)).await;
+            }
+        });
+        assert_eq!(gen.try_resume(()), Err("bad"));
+
+        let (future, airlock, done, poisoned) = gen.into_parts();
+        assert!(done && poisoned);
+        let mut gen = Gen::from_parts(future, airlock, done, poisoned);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| gen.try_resume(())));
+        let payload = result.unwrap_err();
+        assert_eq!(
+            payload.downcast_ref::<&str>(),
+            Some(&"generator resumed after it yielded an error or panicked"),
+        );
+    }
+
+    #[test]
+    fn advance_until() {
+        let mut gen = Gen::new(|mut co: Co<i32, i32>| {
+            async move {
+                let mut n = 0;
+                for _ in 0..5 {
+                    n += co.yield_(n).await;
+                }
+                "done"
+            }
+        });
+        let calls = Cell::new(0);
+        let mut supplier = || {
+            calls.set(calls.get() + 1);
+            3
+        };
+        assert_eq!(
+            gen.advance_until(&mut supplier, |&n| n > 4),
+            Some(GeneratorState::Yielded(6)),
+        );
+        assert_eq!(calls.get(), 3);
+        assert_eq!(
+            gen.advance_until(&mut supplier, |&n| n > 100),
+            Some(GeneratorState::Complete("done")),
+        );
+        assert_eq!(calls.get(), 6);
+        assert_eq!(gen.advance_until(&mut supplier, |_| true), None);
+        assert_eq!(calls.get(), 6);
+    }
+
+    #[test]
+    fn new_from_boxed() {
+        async fn count_from(start: i32, mut co: Co<i32>) -> i32 {
+            co.yield_(start).await;
+            co.yield_(start + 1).await;
+            start
+        }
+
+        let storage = Box::pin(count_from(0, Co::new(sync::Airlock::default())));
+        let airlock = sync::Airlock::default();
+        let mut gen = Gen::new_from_boxed(storage, airlock, |co| count_from(10, co));
+        assert_eq!(gen.resume(), GeneratorState::Yielded(10));
+        assert_eq!(gen.resume(), GeneratorState::Yielded(11));
+        assert_eq!(gen.resume(), GeneratorState::Complete(10));
+    }
+
+    #[test]
+    fn drive_with() {
+        async fn running_total(mut co: Co<i32, i32>) -> i32 {
+            let mut total = 0;
+            for _ in 0..3 {
+                total += co.yield_(total).await;
+            }
+            total
+        }
+
+        // The first input is not observed, so only `2` and `3` are added.
+        let (values, value) = super::drive_with(Gen::new(running_total), vec![1, 2, 3]);
+        assert_eq!(values, [0, 2, 5]);
+        assert_eq!(value, None);
+
+        let (values, value) = super::drive_with(Gen::new(running_total), 1..);
+        assert_eq!(values, [0, 2, 5]);
+        assert_eq!(value, Some(9));
+    }
+
+    #[test]
+    fn last() {
+        let gen = Gen::new(|mut co| {
+            async move {
+                for i in 1..5 {
+                    co.yield_(i).await;
+                }
+                "done"
+            }
+        });
+        assert_eq!(gen.last(), (Some(4), "done"));
+
+        let gen = Gen::new(|_: Co<i32>| async move { "done" });
+        assert_eq!(gen.last(), (None, "done"));
+    }
+
+    #[test]
+    fn resume_n_stops_at_budget() {
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                for i in 0..5 {
+                    co.yield_(i).await;
+                }
+                "done"
+            }
+        });
+        assert_eq!(gen.resume_n((), 2), (vec![0, 1], None));
+        assert_eq!(gen.resume_n((), 2), (vec![2, 3], None));
+        assert_eq!(
+            gen.resume_n((), 2),
+            (vec![4], Some(GeneratorState::Complete("done"))),
+        );
+        assert_eq!(gen.resume_n((), 2), (vec![], None));
+    }
+
+    #[test]
+    fn try_resume_stops_at_error() {
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                co.yield_(Ok(1)).await;
+                co.yield_(Err("bad")).await;
+                co.yield_(Ok(2)).await;
+            }
+        });
+        assert_eq!(gen.try_resume(()), Ok(GeneratorState::Yielded(1)));
+        assert_eq!(gen.try_resume(()), Err("bad"));
+        assert!(gen.is_done());
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| gen.try_resume(())));
+        let payload = result.unwrap_err();
+        assert_eq!(
+            payload.downcast_ref::<&str>(),
+            Some(&"generator resumed after it yielded an error or panicked"),
+        );
+    }
+
+    #[test]
+    fn try_resume_completes() {
+        fn run() -> Result<Vec<i32>, &'static str> {
+            let mut gen = Gen::new(|mut co| {
+                async move {
+                    co.yield_(Ok(1)).await;
+                    co.yield_(Ok(2)).await;
+                    Ok::<_, &str>(())
+                }
+            });
+            let mut xs = Vec::new();
+            while let GeneratorState::Yielded(x) = gen.try_resume(())? {
+                xs.push(x);
+            }
+            Ok(xs)
+        }
+        assert_eq!(run(), Ok(vec![1, 2]));
+    }
+
     #[test]
     fn resume_args() {
         async fn gen(resumes: &RefCell<Vec<&str>>, mut co: Co<i32, &'static str>) {
@@ -432,6 +867,185 @@ mod tests {
         block_on(run_test());
     }
 
+    #[test]
+    fn yield_all() {
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                co.yield_all(vec![10, 20]).await;
+            }
+        });
+        assert_eq!(gen.resume(), GeneratorState::Yielded(10));
+        assert_eq!(gen.resume(), GeneratorState::Yielded(20));
+        assert_eq!(gen.resume(), GeneratorState::Complete(()));
+    }
+
+    #[test]
+    fn yield_all_with() {
+        let mut gen = Gen::new(|mut co: Co<i32, &'static str>| {
+            async move { co.yield_all_with(vec![10, 20]).await }
+        });
+        assert_eq!(gen.resume_with("ignored"), GeneratorState::Yielded(10));
+        assert_eq!(gen.resume_with("abc"), GeneratorState::Yielded(20));
+        assert_eq!(
+            gen.resume_with("def"),
+            GeneratorState::Complete(vec!["abc", "def"]),
+        );
+    }
+
+    #[test]
+    fn yield_while() {
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                let count = Cell::new(0);
+                co.yield_while(
+                    || count.get() < 5,
+                    || {
+                        count.set(count.get() + 1);
+                        count.get()
+                    },
+                )
+                .await;
+                count.get()
+            }
+        });
+        for i in 1..=5 {
+            assert_eq!(gen.resume(), GeneratorState::Yielded(i));
+        }
+        assert_eq!(gen.resume(), GeneratorState::Complete(5));
+    }
+
+    #[test]
+    fn yield_while_with() {
+        let mut gen = Gen::new(|mut co: Co<i32, &'static str>| {
+            async move {
+                let count = Cell::new(0);
+                co.yield_while_with(
+                    || count.get() < 20,
+                    || {
+                        count.set(count.get() + 10);
+                        count.get()
+                    },
+                )
+                .await
+            }
+        });
+        assert_eq!(gen.resume_with("ignored"), GeneratorState::Yielded(10));
+        assert_eq!(gen.resume_with("abc"), GeneratorState::Yielded(20));
+        assert_eq!(
+            gen.resume_with("def"),
+            GeneratorState::Complete(vec!["abc", "def"]),
+        );
+    }
+
+    #[test]
+    fn yield_from_other_flavor() {
+        async fn inner(mut co: crate::rc::Co<i32>) -> &'static str {
+            co.yield_(10).await;
+            co.yield_(20).await;
+            "inner"
+        }
+
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                let ret = co.yield_from(crate::rc::Gen::new(inner)).await;
+                co.yield_(30).await;
+                ret
+            }
+        });
+        assert_eq!(gen.resume(), GeneratorState::Yielded(10));
+        assert_eq!(gen.resume(), GeneratorState::Yielded(20));
+        assert_eq!(gen.resume(), GeneratorState::Yielded(30));
+        assert_eq!(gen.resume(), GeneratorState::Complete("inner"));
+    }
+
+    #[test]
+    fn poll_resume() {
+        async fn produce(mut co: Co<i32, &'static str>) -> &'static str {
+            SlowFuture::new().await;
+            let resume_arg = co.yield_(10).await;
+            SlowFuture::new().await;
+            co.yield_(20).await;
+            resume_arg
+        }
+
+        let mut gen = Gen::new(produce);
+        let mut resume = |arg| {
+            let mut arg = Some(arg);
+            block_on(future::poll_fn(|cx| {
+                Pin::new(&mut gen).poll_resume(cx, arg.take().unwrap_or("dropped"))
+            }))
+        };
+        assert_eq!(resume("ignored"), GeneratorState::Yielded(10));
+        assert_eq!(resume("abc"), GeneratorState::Yielded(20));
+        assert_eq!(resume("def"), GeneratorState::Complete("abc"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn resume_blocking() {
+        let (sender, receiver) = oneshot::channel();
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                co.yield_(1).await;
+                let value = receiver.await.unwrap();
+                co.yield_(value).await;
+                "done"
+            }
+        });
+        assert_eq!(gen.resume_blocking(()), GeneratorState::Yielded(1));
+
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            sender.send(2).unwrap();
+        });
+        assert_eq!(gen.resume_blocking(()), GeneratorState::Yielded(2));
+        assert_eq!(gen.resume_blocking(()), GeneratorState::Complete("done"));
+        assert!(gen.is_done());
+        handle.join().unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn resume_with_catch_unwind() {
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                co.yield_(1).await;
+                co.yield_(2).await;
+                panic!("boom");
+            }
+        });
+        let mut resume = || gen.resume_with_catch_unwind(());
+        assert_eq!(resume().unwrap(), GeneratorState::Yielded(1));
+        assert_eq!(resume().unwrap(), GeneratorState::Yielded(2));
+
+        let payload = resume().unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"boom"));
+        let payload = resume().unwrap_err();
+        assert_eq!(
+            payload.downcast_ref::<&str>(),
+            Some(&"generator resumed after it was done"),
+        );
+        assert!(gen.is_done());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[should_panic(expected = "resumed after it yielded an error or panicked")]
+    fn resume_with_after_caught_panic() {
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                co.yield_(1).await;
+                panic!("boom");
+            }
+        });
+        assert_eq!(
+            gen.resume_with_catch_unwind(()).unwrap(),
+            GeneratorState::Yielded(1)
+        );
+        assert!(gen.resume_with_catch_unwind(()).is_err());
+        gen.resume();
+    }
+
     #[test]
     #[should_panic(expected = "non-async method")]
     fn forbidden_await_helpful_message() {
@@ -0,0 +1,102 @@
+use crate::ops::{Coroutine, GeneratorState};
+use alloc::{boxed::Box, vec::Vec};
+use std::{panic, thread};
+
+/// Runs each coroutine to completion on its own thread, and collects the
+/// values each one yields.
+///
+/// The result has one `Vec` per coroutine, in the same order as `gens`, and
+/// each `Vec` holds that coroutine's values in the order they were yielded.
+/// Return values are discarded. The coroutines run independently of each
+/// other, so the order in which threads make progress does not matter.
+///
+/// This requires the `std` feature.
+///
+/// # Panics
+///
+/// If any coroutine panics, the panic is resumed on the calling thread, after
+/// all the threads have finished.
+///
+/// ```rust
+/// use genawaiter::sync::{par_collect, Gen};
+///
+/// let gens = (0..3).map(|n| Gen::from_iter(vec![n, n * 10])).collect();
+/// assert_eq!(par_collect(gens), [[0, 0], [1, 10], [2, 20]]);
+/// ```
+pub fn par_collect<C>(gens: Vec<C>) -> Vec<Vec<C::Yield>>
+where
+    C: Coroutine<Resume = ()> + Send + 'static,
+    C::Yield: Send + 'static,
+{
+    let handles: Vec<_> = gens
+        .into_iter()
+        .map(|coroutine| thread::spawn(move || drain(coroutine)))
+        .collect();
+    let results: Vec<_> = handles.into_iter().map(thread::JoinHandle::join).collect();
+    results
+        .into_iter()
+        .map(|result| result.unwrap_or_else(|payload| panic::resume_unwind(payload)))
+        .collect()
+}
+
+fn drain<C: Coroutine<Resume = ()>>(coroutine: C) -> Vec<C::Yield> {
+    let mut coroutine = Box::pin(coroutine);
+    let mut values = Vec::new();
+    while let GeneratorState::Yielded(value) = coroutine.as_mut().resume_with(()) {
+        values.push(value);
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sync::{par_collect, Co, Gen};
+    use std::sync::{Arc, Barrier};
+
+    async fn produce(mut co: Co<(usize, char)>, id: usize, barrier: Arc<Barrier>) {
+        co.yield_((id, 'a')).await;
+        // Every generator must reach this point before any can carry on, so
+        // this only finishes if they all run at the same time.
+        barrier.wait();
+        co.yield_((id, 'b')).await;
+    }
+
+    #[test]
+    fn preserves_order() {
+        let barrier = Arc::new(Barrier::new(4));
+        let gens = (0..4)
+            .map(|id| {
+                let barrier = Arc::clone(&barrier);
+                Gen::new(move |co| produce(co, id, barrier))
+            })
+            .collect();
+        let results = par_collect(gens);
+        assert_eq!(results, [
+            [(0, 'a'), (0, 'b')],
+            [(1, 'a'), (1, 'b')],
+            [(2, 'a'), (2, 'b')],
+            [(3, 'a'), (3, 'b')],
+        ],);
+    }
+
+    #[test]
+    fn empty() {
+        let mut gens = vec![Gen::from_iter(Vec::<i32>::new())];
+        assert_eq!(par_collect(gens), [Vec::<i32>::new()]);
+
+        gens = Vec::new();
+        assert!(par_collect(gens).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "boom")]
+    fn propagates_panic() {
+        let gens = vec![Gen::new(|mut co: Co<i32>| {
+            async move {
+                co.yield_(1).await;
+                panic!("boom");
+            }
+        })];
+        par_collect(gens);
+    }
+}
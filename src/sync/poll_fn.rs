@@ -0,0 +1,125 @@
+use crate::sync::{Airlock, Co, Gen};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+impl<Y, R, Ret, P> Gen<Y, R, PollFnFuture<Y, R, P>>
+where
+    P: FnMut(&mut Context<'_>, &Airlock<Y, R>) -> Poll<Ret>,
+{
+    /// Creates a generator which is driven by a hand-written poll function,
+    /// instead of an `async` block.
+    ///
+    /// `f` is called each time the generator is resumed, with the generator's
+    /// [`Airlock`](crate::Airlock). It must do one of two things:
+    ///
+    /// - Store `Next::Yield(value)` in the airlock, and return `Poll::Pending`.
+    /// - Return `Poll::Ready(value)` to complete the generator.
+    ///
+    /// When `f` is called, the airlock holds `Next::Resume(arg)` with the resume
+    /// argument, which `f` may take by storing `Next::Empty`. Unlike with an
+    /// `async` producer, this includes the first resume argument. If `f` needs to
+    /// wait on something else, it can return `Poll::Pending` without yielding,
+    /// and arrange for the context's waker to be woken, but then the generator
+    /// must be driven by an async method such as `async_resume`.
+    ///
+    /// ```rust
+    /// use genawaiter::{sync::Gen, Airlock, GeneratorState, Next};
+    /// use std::task::Poll;
+    ///
+    /// let mut n = 0;
+    /// let mut gen = Gen::from_poll_fn(move |_cx, airlock| {
+    ///     n += 1;
+    ///     if n <= 2 {
+    ///         airlock.replace(Next::Yield(n));
+    ///         Poll::Pending
+    ///     } else {
+    ///         Poll::Ready("done")
+    ///     }
+    /// });
+    /// assert_eq!(gen.resume(), GeneratorState::Yielded(1));
+    /// assert_eq!(gen.resume(), GeneratorState::Yielded(2));
+    /// assert_eq!(gen.resume(), GeneratorState::Complete("done"));
+    /// ```
+    pub fn from_poll_fn(f: P) -> Self {
+        Self::new(|co| PollFnFuture { f, co })
+    }
+}
+
+/// The future inside a generator created by
+/// [`Gen::from_poll_fn`](struct.Gen.html#method.from_poll_fn).
+pub struct PollFnFuture<Y, R, P> {
+    f: P,
+    co: Co<Y, R>,
+}
+
+// No field is structurally pinned.
+impl<Y, R, P> Unpin for PollFnFuture<Y, R, P> {}
+
+impl<Y, R, Ret, P> Future for PollFnFuture<Y, R, P>
+where
+    P: FnMut(&mut Context<'_>, &Airlock<Y, R>) -> Poll<Ret>,
+{
+    type Output = Ret;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        (this.f)(cx, this.co.airlock())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        sync::{Airlock, Gen},
+        Airlock as _,
+        GeneratorState,
+        Next,
+    };
+    use std::task::{Context, Poll};
+
+    #[test]
+    fn yields_three_then_returns() {
+        let mut n = 0;
+        let mut gen = Gen::from_poll_fn(
+            move |_: &mut Context<'_>, airlock: &Airlock<i32, ()>| {
+                if n < 3 {
+                    n += 1;
+                    airlock.replace(Next::Yield(n * 10));
+                    Poll::Pending
+                } else {
+                    Poll::Ready("done")
+                }
+            },
+        );
+        assert_eq!(gen.resume(), GeneratorState::Yielded(10));
+        assert_eq!(gen.resume(), GeneratorState::Yielded(20));
+        assert_eq!(gen.resume(), GeneratorState::Yielded(30));
+        assert_eq!(gen.resume(), GeneratorState::Complete("done"));
+        assert!(gen.is_done());
+    }
+
+    #[test]
+    fn takes_resume_arguments() {
+        let mut total = 0;
+        let mut gen = Gen::from_poll_fn(
+            move |_: &mut Context<'_>, airlock: &Airlock<i32, i32>| {
+                if let Next::Resume(arg) = airlock.replace(Next::Empty) {
+                    total += arg;
+                }
+                if total < 10 {
+                    airlock.replace(Next::Yield(total));
+                    Poll::Pending
+                } else {
+                    Poll::Ready(total)
+                }
+            },
+        );
+        // Unlike with `async` producers, the first resume argument is seen too.
+        assert_eq!(gen.resume_with(1), GeneratorState::Yielded(1));
+        assert_eq!(gen.resume_with(4), GeneratorState::Yielded(5));
+        assert_eq!(gen.resume_with(7), GeneratorState::Complete(12));
+    }
+}
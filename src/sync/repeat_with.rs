@@ -0,0 +1,70 @@
+use crate::sync::{Co, Gen};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+impl<Y, G: FnMut() -> Y> Gen<Y, (), RepeatWithFuture<Y, G>> {
+    /// Creates a generator which yields the result of calling `f`, forever.
+    ///
+    /// This is the generator counterpart of `std::iter::repeat_with`. It never
+    /// completes, so it is usually combined with an adapter such as
+    /// [`take`](crate::Coroutine::take).
+    ///
+    /// [_See the module-level docs for examples._](.)
+    pub fn repeat_with(f: G) -> Self {
+        Self::new(|co| RepeatWithFuture { f, co })
+    }
+}
+
+/// The future inside a generator created by
+/// [`Gen::repeat_with`](struct.Gen.html#method.repeat_with).
+///
+/// Each poll calls the function once and yields the result, so no async block
+/// is needed.
+pub struct RepeatWithFuture<Y, G> {
+    f: G,
+    co: Co<Y>,
+}
+
+// No field is structurally pinned.
+impl<Y, G> Unpin for RepeatWithFuture<Y, G> {}
+
+impl<Y, G: FnMut() -> Y> Future for RepeatWithFuture<Y, G> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        // As in `FromIterFuture`, the future returned by `yield_` can be dropped
+        // once the value is stored.
+        drop(this.co.yield_((this.f)()));
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, GeneratorState},
+        sync::Gen,
+    };
+
+    #[test]
+    fn repeats() {
+        let xs: Vec<_> = Gen::repeat_with(|| 7).take(3).into_iter().collect();
+        assert_eq!(xs, [7, 7, 7]);
+    }
+
+    #[test]
+    fn calls_each_time() {
+        let mut n = 0;
+        let mut gen = Gen::repeat_with(|| {
+            n += 1;
+            n
+        });
+        assert_eq!(gen.resume(), GeneratorState::Yielded(1));
+        assert_eq!(gen.resume(), GeneratorState::Yielded(2));
+        assert!(!gen.is_done());
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{
+    ops::{Coroutine, GeneratorState},
+    sync::{Co, Gen},
+};
+use core::{future::Future, pin::Pin};
+
+/// A generator which can be restarted from the beginning.
+///
+/// This is created by
+/// [`Gen::new_resettable`](struct.Gen.html#method.new_resettable). It holds on
+/// to the producer, and calls it again each time the generator is reset.
+///
+/// [_See the module-level docs for examples._](.)
+pub struct ResettableGen<Y, R, F: Future, P> {
+    generator: Gen<Y, R, F>,
+    producer: P,
+}
+
+impl<Y, R, F: Future> Gen<Y, R, F> {
+    /// Creates a new generator which can be restarted from the beginning.
+    ///
+    /// This works like [`Gen::new`](struct.Gen.html#method.new), except that
+    /// the producer can be called more than once. Each call to
+    /// [`reset`](struct.ResettableGen.html#method.reset) calls it again to
+    /// create a fresh future.
+    pub fn new_resettable<P>(mut producer: P) -> ResettableGen<Y, R, F, P>
+    where
+        P: FnMut(Co<Y, R>) -> F,
+    {
+        let generator = Gen::new(&mut producer);
+        ResettableGen {
+            generator,
+            producer,
+        }
+    }
+}
+
+impl<Y, R, F: Future, P: FnMut(Co<Y, R>) -> F> ResettableGen<Y, R, F, P> {
+    /// Restarts the generator from the beginning.
+    ///
+    /// If the generator was partway through, the future is dropped, along with
+    /// any values it holds. The next resume starts a fresh future from the
+    /// producer. The existing allocations are reused.
+    pub fn reset(&mut self) {
+        self.generator.reset_with(&mut self.producer);
+    }
+
+    /// Resumes execution of the generator.
+    ///
+    /// This works exactly the same as
+    /// [`Gen::resume_with`](struct.Gen.html#method.resume_with).
+    pub fn resume_with(&mut self, arg: R) -> GeneratorState<Y, F::Output> {
+        self.generator.resume_with(arg)
+    }
+
+    /// Returns `true` if the generator has completed since it was last reset.
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        self.generator.is_done()
+    }
+}
+
+impl<Y, F: Future, P: FnMut(Co<Y, ()>) -> F> ResettableGen<Y, (), F, P> {
+    /// Resumes execution of the generator.
+    ///
+    /// This works exactly the same as
+    /// [`Gen::resume`](struct.Gen.html#method.resume).
+    pub fn resume(&mut self) -> GeneratorState<Y, F::Output> {
+        self.resume_with(())
+    }
+}
+
+impl<Y, R, F: Future, P: FnMut(Co<Y, R>) -> F> Coroutine for ResettableGen<Y, R, F, P> {
+    type Yield = Y;
+    type Resume = R;
+    type Return = F::Output;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: R,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        // Safety: No field is structurally pinned (the future is pinned in its own
+        // allocation by `Gen`), and `ResettableGen::resume_with` does not move
+        // `self`.
+        let this = unsafe { self.get_unchecked_mut() };
+        this.resume_with(arg)
+    }
+}
+
+impl<Y, R: Clone, F: Future, P: FnMut(Co<Y, R>) -> F> ResettableGen<Y, R, F, P> {
+    /// Creates a generator which starts over from the beginning each time this
+    /// one completes, so it yields forever.
+    ///
+    /// As with [`reset`](#method.reset), the existing allocations are reused.
+    pub fn cycle(self) -> Cycle<Y, R, F, P> {
+        Cycle { generator: self }
+    }
+}
+
+/// A generator which starts over from the beginning each time it completes.
+///
+/// This is created by
+/// [`ResettableGen::cycle`](struct.ResettableGen.html#method.cycle).
+///
+/// [_See the module-level docs for examples._](.)
+pub struct Cycle<Y, R, F: Future, P> {
+    generator: ResettableGen<Y, R, F, P>,
+}
+
+impl<Y, R: Clone, F: Future, P: FnMut(Co<Y, R>) -> F> Cycle<Y, R, F, P> {
+    /// Resumes execution of the generator.
+    ///
+    /// If the generator completes, it is reset and resumed again with a clone
+    /// of `arg`, so a value is yielded instead. Only if the generator completes
+    /// again right after being reset, without yielding anything, is `Complete`
+    /// returned.
+    pub fn resume_with(&mut self, arg: R) -> GeneratorState<Y, F::Output> {
+        match self.generator.resume_with(arg.clone()) {
+            GeneratorState::Yielded(value) => GeneratorState::Yielded(value),
+            GeneratorState::Complete(_) => {
+                self.generator.reset();
+                self.generator.resume_with(arg)
+            }
+        }
+    }
+}
+
+impl<Y, F: Future, P: FnMut(Co<Y, ()>) -> F> Cycle<Y, (), F, P> {
+    /// Resumes execution of the generator.
+    ///
+    /// This works exactly the same as
+    /// [`resume_with`](struct.Cycle.html#method.resume_with).
+    pub fn resume(&mut self) -> GeneratorState<Y, F::Output> {
+        self.resume_with(())
+    }
+}
+
+impl<Y, R: Clone, F: Future, P: FnMut(Co<Y, R>) -> F> Coroutine for Cycle<Y, R, F, P> {
+    type Yield = Y;
+    type Resume = R;
+    type Return = F::Output;
+
+    fn resume_with(
+        self: Pin<&mut Self>,
+        arg: R,
+    ) -> GeneratorState<Self::Yield, Self::Return> {
+        // Safety: As with `ResettableGen`, no field is structurally pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        this.resume_with(arg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{Coroutine, GeneratorState},
+        sync::{Co, Gen},
+    };
+
+    async fn produce(mut co: Co<i32>) -> &'static str {
+        co.yield_(10).await;
+        co.yield_(20).await;
+        "done"
+    }
+
+    #[test]
+    fn reset_after_complete() {
+        let mut gen = Gen::new_resettable(produce);
+        assert_eq!(gen.resume(), GeneratorState::Yielded(10));
+        assert_eq!(gen.resume(), GeneratorState::Yielded(20));
+        assert_eq!(gen.resume(), GeneratorState::Complete("done"));
+        assert!(gen.is_done());
+
+        gen.reset();
+        assert!(!gen.is_done());
+        assert_eq!(gen.resume(), GeneratorState::Yielded(10));
+    }
+
+    #[test]
+    fn cycle() {
+        let gen = Gen::new_resettable(|mut co| {
+            async move {
+                co.yield_all(vec![1, 2]).await;
+            }
+        });
+        let xs: Vec<_> = gen.cycle().take(5).into_iter().collect();
+        assert_eq!(xs, [1, 2, 1, 2, 1]);
+    }
+
+    #[test]
+    fn cycle_empty() {
+        let mut gen = Gen::new_resettable(|_: Co<i32>| async move { "done" }).cycle();
+        assert_eq!(gen.resume(), GeneratorState::Complete("done"));
+    }
+
+    #[test]
+    fn reset_mid_stream() {
+        let mut runs = 0;
+        let mut gen = Gen::new_resettable(|mut co| {
+            runs += 1;
+            let run = runs;
+            async move {
+                co.yield_(run).await;
+                co.yield_(run * 10).await;
+            }
+        });
+        assert_eq!(gen.resume(), GeneratorState::Yielded(1));
+
+        gen.reset();
+        assert_eq!(gen.resume(), GeneratorState::Yielded(2));
+        assert_eq!(gen.resume(), GeneratorState::Yielded(20));
+        assert_eq!(gen.resume(), GeneratorState::Complete(()));
+    }
+}
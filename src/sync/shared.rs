@@ -0,0 +1,207 @@
+use crate::{
+    ops::GeneratorState,
+    sync::{Co, Gen},
+};
+use core::future::Future;
+use std::sync::Mutex;
+
+/// A generator which can be resumed through a shared reference.
+///
+/// The generator is kept behind a `Mutex`, so an `Arc<SharedGen<..>>` can be
+/// cloned into several threads which take turns resuming it. Resumes are
+/// serialized by the mutex: while one thread is resuming the generator, any
+/// other thread which tries to resume it blocks until the first one is done.
+/// The generator never runs on two threads at once.
+///
+/// Exactly one thread sees `Complete`. Whether the generator has completed is
+/// checked while the mutex is held, so any resume after that returns `None`
+/// instead of polling the finished generator, however the threads race.
+///
+/// This requires the `std` feature.
+///
+/// ```rust
+/// use genawaiter::sync::{Co, SharedGen};
+/// use std::{sync::Arc, thread};
+///
+/// async fn counter(mut co: Co<i32>) {
+///     for n in 0..10 {
+///         co.yield_(n).await;
+///     }
+/// }
+///
+/// let gen = Arc::new(SharedGen::new(counter));
+/// let other = Arc::clone(&gen);
+/// let handle = thread::spawn(move || other.resume().unwrap().yielded());
+/// let first = gen.resume().unwrap().yielded();
+/// let second = handle.join().unwrap();
+/// assert_eq!(first.unwrap() + second.unwrap(), 1);
+/// ```
+pub struct SharedGen<Y, R, F: Future> {
+    generator: Mutex<Gen<Y, R, F>>,
+}
+
+impl<Y, R, F: Future> SharedGen<Y, R, F> {
+    /// Creates a new shared generator from a function.
+    ///
+    /// This works like [`Gen::new`](struct.Gen.html#method.new).
+    pub fn new(producer: impl FnOnce(Co<Y, R>) -> F) -> Self {
+        Self::from(Gen::new(producer))
+    }
+
+    /// Resumes execution of the generator.
+    ///
+    /// This blocks the current thread while another thread is resuming the
+    /// generator. Returns `None`, without resuming it, if the generator has
+    /// already completed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a previous resume panicked while the generator was locked.
+    pub fn resume_with(&self, arg: R) -> Option<GeneratorState<Y, F::Output>> {
+        let mut generator = self.generator.lock().unwrap();
+        if generator.is_done() {
+            return None;
+        }
+        Some(generator.resume_with(arg))
+    }
+
+    /// Returns `true` if the generator has completed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a previous resume panicked while the generator was locked.
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        self.generator.lock().unwrap().is_done()
+    }
+
+    /// Returns the generator inside.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a previous resume panicked while the generator was locked.
+    #[must_use]
+    pub fn into_inner(self) -> Gen<Y, R, F> {
+        self.generator.into_inner().unwrap()
+    }
+}
+
+impl<Y, F: Future> SharedGen<Y, (), F> {
+    /// Resumes execution of the generator.
+    ///
+    /// This blocks the current thread while another thread is resuming the
+    /// generator. Returns `None`, without resuming it, if the generator has
+    /// already completed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a previous resume panicked while the generator was locked.
+    pub fn resume(&self) -> Option<GeneratorState<Y, F::Output>> {
+        self.resume_with(())
+    }
+}
+
+impl<Y, R, F: Future> From<Gen<Y, R, F>> for SharedGen<Y, R, F> {
+    fn from(generator: Gen<Y, R, F>) -> Self {
+        Self {
+            generator: Mutex::new(generator),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        sync::{Co, SharedGen},
+        GeneratorState,
+    };
+    use std::{sync::Arc, thread};
+
+    async fn counter(mut co: Co<i32>) {
+        let mut n = 0;
+        loop {
+            co.yield_(n).await;
+            n += 1;
+        }
+    }
+
+    #[test]
+    fn threads_take_turns() {
+        let gen = Arc::new(SharedGen::new(counter));
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let gen = Arc::clone(&gen);
+                thread::spawn(move || {
+                    (0..50)
+                        .map(|_| gen.resume().unwrap().yielded().unwrap())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut all = Vec::new();
+        for handle in handles {
+            let values = handle.join().unwrap();
+            // Each thread sees the values in the order they were yielded.
+            assert!(values.windows(2).all(|w| w[0] < w[1]));
+            all.extend(values);
+        }
+        all.sort_unstable();
+        assert_eq!(all, (0..100).collect::<Vec<_>>());
+        assert_eq!(gen.resume(), Some(GeneratorState::Yielded(100)));
+    }
+
+    #[test]
+    fn passes_resume_arguments() {
+        let gen = SharedGen::new(|mut co: Co<(), i32>| {
+            async move {
+                let n = co.yield_(()).await;
+                n * 2
+            }
+        });
+        assert_eq!(gen.resume_with(0), Some(GeneratorState::Yielded(())));
+        assert_eq!(gen.resume_with(21), Some(GeneratorState::Complete(42)));
+        assert!(gen.into_inner().is_done());
+    }
+
+    #[test]
+    fn threads_race_past_the_end() {
+        let gen = Arc::new(SharedGen::new(|mut co: Co<i32>| {
+            async move {
+                for n in 0..10 {
+                    co.yield_(n).await;
+                }
+                "done"
+            }
+        }));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let gen = Arc::clone(&gen);
+                // Each thread keeps resuming until it sees the end, so several of
+                // them resume the generator after it has completed.
+                thread::spawn(move || {
+                    let mut states = Vec::new();
+                    while let Some(state) = gen.resume() {
+                        states.push(state);
+                    }
+                    states
+                })
+            })
+            .collect();
+
+        let mut yielded = Vec::new();
+        let mut completed = Vec::new();
+        for handle in handles {
+            for state in handle.join().unwrap() {
+                match state {
+                    GeneratorState::Yielded(n) => yielded.push(n),
+                    GeneratorState::Complete(value) => completed.push(value),
+                }
+            }
+        }
+        yielded.sort_unstable();
+        assert_eq!(yielded, (0..10).collect::<Vec<_>>());
+        assert_eq!(completed, ["done"]);
+        assert_eq!(gen.resume(), None);
+    }
+}
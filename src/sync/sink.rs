@@ -0,0 +1,169 @@
+use crate::{
+    ops::GeneratorState,
+    sync::{Co, Gen},
+};
+use core::future::Future;
+
+/// A wrapper which lets values be pushed into a generator, for generators which
+/// consume their resume arguments.
+///
+/// The generator is resumed with `Some(value)` for each pushed value, and with
+/// `None` once the input is finished, after which it should complete. It is
+/// started on the first push, and whatever it yields before receiving its
+/// first value is discarded.
+///
+/// ```rust
+/// use genawaiter::sync::{Co, GenSink};
+///
+/// async fn sum(mut co: Co<i32, Option<i32>>) -> i32 {
+///     let mut total = 0;
+///     while let Some(n) = co.yield_(total).await {
+///         total += n;
+///     }
+///     total
+/// }
+///
+/// let mut sink = GenSink::new(sum);
+/// assert_eq!(sink.push(1), Some(1));
+/// assert_eq!(sink.push(2), Some(3));
+/// assert_eq!(sink.finish(), 3);
+/// ```
+pub struct GenSink<Y, R, F: Future> {
+    generator: Gen<Y, Option<R>, F>,
+    started: bool,
+    returned: Option<F::Output>,
+}
+
+impl<Y, R, F: Future> GenSink<Y, R, F> {
+    /// Creates a new sink from a function.
+    ///
+    /// This works like [`Gen::new`](struct.Gen.html#method.new).
+    pub fn new(producer: impl FnOnce(Co<Y, Option<R>>) -> F) -> Self {
+        Self::from(Gen::new(producer))
+    }
+
+    /// Pushes a value into the generator.
+    ///
+    /// Returns what the generator yielded in response. If the generator has
+    /// completed, `None` is returned, and the value is dropped.
+    pub fn push(&mut self, value: R) -> Option<Y> {
+        if !self.start() {
+            return None;
+        }
+        match self.generator.resume_with(Some(value)) {
+            GeneratorState::Yielded(y) => Some(y),
+            GeneratorState::Complete(r) => {
+                self.returned = Some(r);
+                None
+            }
+        }
+    }
+
+    /// Tells the generator that there are no more values, and runs it to
+    /// completion.
+    ///
+    /// The generator is resumed with `None` until it completes, and any values
+    /// it yields along the way are dropped. Returns the generator's return
+    /// value.
+    pub fn finish(mut self) -> F::Output {
+        if let Some(value) = self.returned.take() {
+            return value;
+        }
+        loop {
+            if let GeneratorState::Complete(value) = self.generator.resume_with(None) {
+                return value;
+            }
+        }
+    }
+
+    /// Returns `true` if the generator has completed.
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        self.returned.is_some()
+    }
+
+    /// Runs the generator up to its first yield, if it has not been started
+    /// yet. Returns `false` if it has completed.
+    fn start(&mut self) -> bool {
+        if !self.started {
+            self.started = true;
+            // The first resume argument is never observed, so this `None` does
+            // not end the input.
+            if let GeneratorState::Complete(r) = self.generator.resume_with(None) {
+                self.returned = Some(r);
+            }
+        }
+        self.returned.is_none()
+    }
+}
+
+impl<Y, R, F: Future> From<Gen<Y, Option<R>, F>> for GenSink<Y, R, F> {
+    fn from(generator: Gen<Y, Option<R>, F>) -> Self {
+        Self {
+            generator,
+            started: false,
+            returned: None,
+        }
+    }
+}
+
+/// Pushes each value in turn, dropping whatever the generator yields in
+/// response.
+impl<Y, R, F: Future> Extend<R> for GenSink<Y, R, F> {
+    fn extend<I: IntoIterator<Item = R>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sync::{Co, GenSink};
+
+    async fn accumulate(mut co: Co<i32, Option<i32>>) -> i32 {
+        let mut total = 0;
+        while let Some(n) = co.yield_(total).await {
+            total += n;
+        }
+        total
+    }
+
+    #[test]
+    fn accumulates_pushed_values() {
+        let mut sink = GenSink::new(accumulate);
+        assert_eq!(sink.push(1), Some(1));
+        assert_eq!(sink.push(2), Some(3));
+        assert_eq!(sink.push(3), Some(6));
+        assert!(!sink.is_done());
+        assert_eq!(sink.finish(), 6);
+    }
+
+    #[test]
+    fn extend() {
+        let mut sink = GenSink::new(accumulate);
+        sink.extend(1..=4);
+        assert_eq!(sink.push(5), Some(15));
+        assert_eq!(sink.finish(), 15);
+    }
+
+    #[test]
+    fn finish_without_pushing() {
+        let sink = GenSink::new(accumulate);
+        assert_eq!(sink.finish(), 0);
+    }
+
+    #[test]
+    fn completes_early() {
+        let mut sink = GenSink::new(|mut co: Co<(), Option<i32>>| {
+            async move {
+                let first = co.yield_(()).await;
+                first.unwrap() * 10
+            }
+        });
+        assert_eq!(sink.push(4), None);
+        assert!(sink.is_done());
+        assert_eq!(sink.push(5), None);
+        assert_eq!(sink.finish(), 40);
+    }
+}
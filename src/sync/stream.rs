@@ -1,34 +1,95 @@
-use crate::{ops::GeneratorState, sync::Gen};
+use crate::{
+    ops::GeneratorState,
+    sync::{Co, Gen},
+};
+use core::{future::Future, pin::Pin};
 use futures_core::{
     task::{Context, Poll},
     Stream,
 };
-use std::{future::Future, pin::Pin};
 
-impl<Y, F: Future<Output = ()>> Stream for Gen<Y, (), F> {
+impl<Y, R> Co<Y, R> {
+    /// Awaits a future, and then yields its output from the generator.
+    ///
+    /// Returns the resume argument, the same as `yield_`. Since this awaits
+    /// another future, the generator must be driven with a real waker, e.g. as
+    /// a `Stream` or with [`Gen::poll_resume`](struct.Gen.html#method.poll_resume).
+    ///
+    /// The caller should immediately `await` the result of this function.
+    pub async fn yield_with<Fut: Future<Output = Y>>(&mut self, fut: Fut) -> R {
+        let value = fut.await;
+        self.yield_(value).await
+    }
+}
+
+impl<Y, F: Future> Stream for Gen<Y, (), F> {
+    type Item = Y;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.poll_resume(cx, ()).map(|state| {
+            match state {
+                GeneratorState::Yielded(x) => Some(x),
+                GeneratorState::Complete(_) => None,
+            }
+        })
+    }
+}
+
+/// A stream over the values yielded by a generator.
+///
+/// This is created by
+/// [`Gen::into_stream`](struct.Gen.html#method.into_stream). Unlike an
+/// `impl Stream`, this type can be named, e.g. to store it in a struct field.
+///
+/// This requires the `futures03` feature.
+pub struct GenStream<Y, F: Future> {
+    generator: Gen<Y, (), F>,
+}
+
+impl<Y, F: Future> Gen<Y, (), F> {
+    /// Converts the generator into a [`GenStream`].
+    ///
+    /// This requires the `futures03` feature.
+    pub fn into_stream(self) -> GenStream<Y, F> {
+        GenStream { generator: self }
+    }
+}
+
+impl<Y, F: Future> GenStream<Y, F> {
+    /// Returns the generator inside.
+    pub fn into_inner(self) -> Gen<Y, (), F> {
+        self.generator
+    }
+}
+
+impl<Y, F: Future> Stream for GenStream<Y, F> {
     type Item = Y;
 
     fn poll_next(
         mut self: Pin<&mut Self>,
         cx: &mut Context<'_>,
     ) -> Poll<Option<Self::Item>> {
-        let fut = self.async_resume();
-        pin_mut!(fut);
-        match fut.poll(cx) {
-            Poll::Ready(GeneratorState::Yielded(x)) => Poll::Ready(Some(x)),
-            Poll::Ready(GeneratorState::Complete(())) => Poll::Ready(None),
-            Poll::Pending => Poll::Pending,
-        }
+        Pin::new(&mut self.generator).poll_next(cx)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        sync::{Co, Gen},
+        sync::{Co, Gen, GenStream},
         testing::SlowFuture,
     };
-    use futures::{executor::block_on_stream, stream};
+    use futures::{
+        channel::oneshot,
+        executor::{block_on, block_on_stream},
+        future,
+        stream,
+        StreamExt,
+    };
+    use std::{future::Future, thread};
 
     #[test]
     fn blocking() {
@@ -56,4 +117,59 @@ mod tests {
         let items: Vec<_> = block_on_stream(gen).collect();
         assert_eq!(items, [10, 20]);
     }
+
+    #[test]
+    fn yield_with() {
+        async fn produce(mut co: Co<i32>) {
+            co.yield_with(future::ready(10)).await;
+            co.yield_with(async {
+                SlowFuture::new().await;
+                20
+            })
+            .await;
+        }
+
+        let gen = Gen::new(produce);
+        let items: Vec<_> = block_on_stream(gen).collect();
+        assert_eq!(items, [10, 20]);
+    }
+
+    #[test]
+    fn drain_to_vec() {
+        let (sender, receiver) = oneshot::channel();
+        let mut gen = Gen::new(|mut co| {
+            async move {
+                co.yield_(10).await;
+                let value = receiver.await.unwrap();
+                co.yield_(value).await;
+                "done"
+            }
+        });
+
+        let handle = thread::spawn(move || sender.send(20).unwrap());
+        let (items, value) = block_on(gen.drain_to_vec());
+        handle.join().unwrap();
+        assert_eq!(items, [10, 20]);
+        assert_eq!(value, "done");
+        assert!(gen.is_done());
+    }
+
+    #[test]
+    fn into_stream() {
+        async fn produce(mut co: Co<i32>) {
+            co.yield_(10).await;
+            SlowFuture::new().await;
+            co.yield_(20).await;
+        }
+
+        struct Holder<F: Future> {
+            stream: GenStream<i32, F>,
+        }
+
+        let holder = Holder {
+            stream: Gen::new(produce).into_stream(),
+        };
+        let items: Vec<_> = block_on(holder.stream.collect());
+        assert_eq!(items, [10, 20]);
+    }
 }
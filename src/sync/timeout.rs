@@ -0,0 +1,202 @@
+use core::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use std::{
+    error::Error,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+        Mutex,
+    },
+    thread::{self, Thread},
+    time::{Duration, Instant},
+};
+
+/// The error returned when a generator did not yield or complete before a
+/// timeout.
+///
+/// This is returned by
+/// [`Gen::resume_with_timeout`](struct.Gen.html#method.resume_with_timeout).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TimedOut;
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the generator did not yield or complete in time")
+    }
+}
+
+impl Error for TimedOut {}
+
+/// Runs a future until it finishes, or until a duration has passed.
+pub(crate) struct Timeout<T> {
+    future: T,
+    deadline: Instant,
+    timer: Option<(Arc<Timer>, Thread)>,
+}
+
+/// The state shared with the helper thread which waits for the deadline.
+struct Timer {
+    waker: Mutex<Waker>,
+    cancelled: AtomicBool,
+}
+
+impl<T: Future> Timeout<T> {
+    pub(crate) fn new(future: T, duration: Duration) -> Self {
+        Self {
+            future,
+            deadline: Instant::now() + duration,
+            timer: None,
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn project(
+        self: Pin<&mut Self>,
+    ) -> (Pin<&mut T>, Instant, &mut Option<(Arc<Timer>, Thread)>) {
+        // Safety: `future` is structurally pinned, and the other fields are not.
+        // Nothing is moved.
+        unsafe {
+            let this = self.get_unchecked_mut();
+            (
+                Pin::new_unchecked(&mut this.future),
+                this.deadline,
+                &mut this.timer,
+            )
+        }
+    }
+}
+
+impl<T: Future> Future for Timeout<T> {
+    type Output = Result<T::Output, TimedOut>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let (future, deadline, timer) = self.project();
+        // The future is polled first, so a value which is ready right at the
+        // deadline still wins.
+        if let Poll::Ready(value) = future.poll(cx) {
+            return Poll::Ready(Ok(value));
+        }
+        if Instant::now() >= deadline {
+            return Poll::Ready(Err(TimedOut));
+        }
+        if let Some((timer, _)) = timer {
+            timer.waker.lock().unwrap().clone_from(cx.waker());
+        } else {
+            // A helper thread waits until the deadline, then wakes whichever task
+            // most recently polled this future. It is only started once, and it
+            // stops early if this is dropped first.
+            let shared = Arc::new(Timer {
+                waker: Mutex::new(cx.waker().clone()),
+                cancelled: AtomicBool::new(false),
+            });
+            let helper = Arc::clone(&shared);
+            let handle = thread::spawn(move || wait(&helper, deadline));
+            *timer = Some((shared, handle.thread().clone()));
+        }
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for Timeout<T> {
+    fn drop(&mut self) {
+        if let Some((timer, thread)) = &self.timer {
+            timer.cancelled.store(true, Ordering::Release);
+            thread.unpark();
+        }
+    }
+}
+
+fn wait(timer: &Timer, deadline: Instant) {
+    loop {
+        if timer.cancelled.load(Ordering::Acquire) {
+            return;
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            break;
+        }
+        // This can wake up spuriously, or because the timeout was dropped, so
+        // both are checked again each time around.
+        thread::park_timeout(deadline - now);
+    }
+    timer.waker.lock().unwrap().wake_by_ref();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        sync::{timeout::Timeout, Co, Gen, TimedOut},
+        testing::SlowFuture,
+        GeneratorState,
+    };
+    use futures::{executor::block_on, future, task::noop_waker_ref};
+    use std::{
+        future::Future,
+        sync::Arc,
+        task::{Context, Poll},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    #[test]
+    fn times_out_on_pending_work() {
+        let mut gen = Gen::new(|mut co: Co<i32>| {
+            async move {
+                co.yield_(1).await;
+                future::pending::<()>().await;
+            }
+        });
+        let timeout = Duration::from_millis(20);
+
+        let state = block_on(gen.resume_with_timeout((), timeout));
+        assert_eq!(state, Ok(GeneratorState::Yielded(1)));
+
+        let start = Instant::now();
+        let state = block_on(gen.resume_with_timeout((), timeout));
+        assert_eq!(state, Err(TimedOut));
+        assert!(start.elapsed() >= timeout);
+        assert!(!gen.is_done());
+    }
+
+    #[test]
+    fn finishes_in_time() {
+        let mut gen = Gen::new(|mut co: Co<i32>| {
+            async move {
+                SlowFuture::new().await;
+                co.yield_(1).await;
+                "done"
+            }
+        });
+        let timeout = Duration::from_secs(10);
+
+        let state = block_on(gen.resume_with_timeout((), timeout));
+        assert_eq!(state, Ok(GeneratorState::Yielded(1)));
+        let state = block_on(gen.resume_with_timeout((), timeout));
+        assert_eq!(state, Ok(GeneratorState::Complete("done")));
+        assert!(gen.is_done());
+    }
+
+    #[test]
+    fn drop_stops_the_timer() {
+        let mut timeout = Box::pin(Timeout::new(
+            future::pending::<()>(),
+            Duration::from_secs(100),
+        ));
+        let mut cx = Context::from_waker(noop_waker_ref());
+        assert_eq!(timeout.as_mut().poll(&mut cx), Poll::Pending);
+        let timer = Arc::clone(&timeout.timer.as_ref().unwrap().0);
+        drop(timeout);
+
+        // The helper thread lets go of its clone of the timer once it stops, long
+        // before the deadline.
+        let start = Instant::now();
+        while Arc::strong_count(&timer) > 1 {
+            assert!(start.elapsed() < Duration::from_secs(10));
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+}
@@ -1,7 +1,14 @@
-use std::{
+#[cfg(feature = "std")]
+use core::mem::{self, ManuallyDrop};
+use core::{
     ptr,
     task::{RawWaker, RawWakerVTable, Waker},
 };
+#[cfg(feature = "std")]
+use std::{
+    sync::Arc,
+    thread::{self, Thread},
+};
 
 pub fn create() -> Waker {
     // Safety: The waker points to a vtable with functions that do nothing. Doing
@@ -21,3 +28,41 @@ unsafe fn wake(_: *const ()) {}
 unsafe fn wake_by_ref(_: *const ()) {}
 
 unsafe fn drop(_: *const ()) {}
+
+/// Creates a waker which unparks the current thread.
+#[cfg(feature = "std")]
+pub fn current_thread() -> Waker {
+    let thread = Arc::new(thread::current());
+    let raw = RawWaker::new(Arc::into_raw(thread).cast::<()>(), &THREAD_VTABLE);
+    // Safety: The data pointer came from `Arc::into_raw`, and every function in
+    // the vtable treats it as one strong reference to the `Arc<Thread>`.
+    unsafe { Waker::from_raw(raw) }
+}
+
+#[cfg(feature = "std")]
+const THREAD_VTABLE: RawWakerVTable =
+    RawWakerVTable::new(thread_clone, thread_wake, thread_wake_by_ref, thread_drop);
+
+#[cfg(feature = "std")]
+unsafe fn thread_clone(data: *const ()) -> RawWaker {
+    let thread = ManuallyDrop::new(Arc::from_raw(data.cast::<Thread>()));
+    let data = Arc::into_raw(Arc::clone(&thread)).cast::<()>();
+    RawWaker::new(data, &THREAD_VTABLE)
+}
+
+#[cfg(feature = "std")]
+unsafe fn thread_wake(data: *const ()) {
+    let thread = Arc::from_raw(data.cast::<Thread>());
+    thread.unpark();
+}
+
+#[cfg(feature = "std")]
+unsafe fn thread_wake_by_ref(data: *const ()) {
+    let thread = ManuallyDrop::new(Arc::from_raw(data.cast::<Thread>()));
+    thread.unpark();
+}
+
+#[cfg(feature = "std")]
+unsafe fn thread_drop(data: *const ()) {
+    mem::drop(Arc::from_raw(data.cast::<Thread>()));
+}
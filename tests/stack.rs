@@ -37,6 +37,22 @@ fn test_stream() {
     assert_eq!(xs, [1, 3, 5, 7, 9]);
 }
 
+#[cfg(feature = "proc_macro")]
+#[test]
+fn stack_let_gen_resume_type() {
+    use genawaiter::{stack::let_gen, yield_, GeneratorState};
+
+    let_gen!(gen, resume = i32, {
+        let mut sum = 0;
+        loop {
+            sum += yield_!(sum);
+        }
+    });
+    assert_eq!(gen.resume_with(100), GeneratorState::Yielded(0));
+    assert_eq!(gen.resume_with(1), GeneratorState::Yielded(1));
+    assert_eq!(gen.resume_with(2), GeneratorState::Yielded(3));
+}
+
 #[cfg(feature = "proc_macro")]
 #[test]
 fn stack_proc_macro_fn() {
@@ -34,6 +34,27 @@ fn test_stream() {
     assert_eq!(xs, [1, 3, 5, 7, 9]);
 }
 
+#[cfg(feature = "futures03")]
+#[test]
+fn test_stream_awaiting_channel() {
+    use futures::{channel::oneshot, executor::block_on, future, StreamExt};
+
+    let (sender, receiver) = oneshot::channel();
+    let gen = Gen::new(|mut co| {
+        async move {
+            co.yield_(1).await;
+            let n = receiver.await.unwrap();
+            co.yield_(n).await;
+        }
+    });
+    // The generator gets stuck awaiting the receiver until the second future sends
+    // a value, which wakes the task back up.
+    let (xs, ()) = block_on(future::join(gen.collect::<Vec<_>>(), async move {
+        sender.send(2).unwrap();
+    }));
+    assert_eq!(xs, [1, 2]);
+}
+
 #[cfg(feature = "proc_macro")]
 #[test]
 fn sync_proc_macro_fn() {